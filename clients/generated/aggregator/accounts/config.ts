@@ -25,6 +25,10 @@ import {
   getStructEncoder,
   getU16Decoder,
   getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
@@ -40,6 +44,12 @@ import {
   type MaybeEncodedAccount,
   type ReadonlyUint8Array,
 } from '@solana/kit';
+import {
+  getFeeSideDecoder,
+  getFeeSideEncoder,
+  type FeeSide,
+  type FeeSideArgs,
+} from '../types';
 
 export const CONFIG_DISCRIMINATOR = new Uint8Array([
   155, 12, 170, 224, 30, 250, 204, 130,
@@ -55,6 +65,29 @@ export type Config = {
   feeBps: number;
   paused: boolean;
   bump: number;
+  /** Admin proposed via `propose_admin`; `Pubkey::default()` when none. */
+  pendingAdmin: Address;
+  /**
+   * Owner of the token accounts `withdraw_fees` may pay out to;
+   * `Pubkey::default()` falls back to `admin`.
+   */
+  feeRecipient: Address;
+  /** Upper bound on `RouteOptions::referral_bps`; 0 disables referrals. */
+  maxReferralBps: number;
+  /** Share of output above `RouteOptions::quoted_out` taken as a surplus fee. */
+  surplusShareBps: number;
+  /** Program that must own the price feeds passed to routes. */
+  oracleProgram: Address;
+  /** Largest shortfall of the realized rate against the oracle rate. */
+  maxPriceDeviationBps: number;
+  /** Oldest price, in slots, the oracle guard accepts. */
+  maxOracleStalenessSlots: bigint;
+  /** Mint routes charge the protocol fee in. */
+  feeSide: FeeSide;
+  /** Reject routes that do not pass price feeds for the oracle guard. */
+  requireOracle: boolean;
+  /** Per-DEX pause switches, one bit per [`DexId`] discriminant. */
+  pausedDexes: number;
 };
 
 export type ConfigArgs = {
@@ -62,6 +95,29 @@ export type ConfigArgs = {
   feeBps: number;
   paused: boolean;
   bump: number;
+  /** Admin proposed via `propose_admin`; `Pubkey::default()` when none. */
+  pendingAdmin: Address;
+  /**
+   * Owner of the token accounts `withdraw_fees` may pay out to;
+   * `Pubkey::default()` falls back to `admin`.
+   */
+  feeRecipient: Address;
+  /** Upper bound on `RouteOptions::referral_bps`; 0 disables referrals. */
+  maxReferralBps: number;
+  /** Share of output above `RouteOptions::quoted_out` taken as a surplus fee. */
+  surplusShareBps: number;
+  /** Program that must own the price feeds passed to routes. */
+  oracleProgram: Address;
+  /** Largest shortfall of the realized rate against the oracle rate. */
+  maxPriceDeviationBps: number;
+  /** Oldest price, in slots, the oracle guard accepts. */
+  maxOracleStalenessSlots: number | bigint;
+  /** Mint routes charge the protocol fee in. */
+  feeSide: FeeSideArgs;
+  /** Reject routes that do not pass price feeds for the oracle guard. */
+  requireOracle: boolean;
+  /** Per-DEX pause switches, one bit per [`DexId`] discriminant. */
+  pausedDexes: number;
 };
 
 export function getConfigEncoder(): FixedSizeEncoder<ConfigArgs> {
@@ -72,6 +128,16 @@ export function getConfigEncoder(): FixedSizeEncoder<ConfigArgs> {
       ['feeBps', getU16Encoder()],
       ['paused', getBooleanEncoder()],
       ['bump', getU8Encoder()],
+      ['pendingAdmin', getAddressEncoder()],
+      ['feeRecipient', getAddressEncoder()],
+      ['maxReferralBps', getU16Encoder()],
+      ['surplusShareBps', getU16Encoder()],
+      ['oracleProgram', getAddressEncoder()],
+      ['maxPriceDeviationBps', getU16Encoder()],
+      ['maxOracleStalenessSlots', getU64Encoder()],
+      ['feeSide', getFeeSideEncoder()],
+      ['requireOracle', getBooleanEncoder()],
+      ['pausedDexes', getU32Encoder()],
     ]),
     (value) => ({ ...value, discriminator: CONFIG_DISCRIMINATOR })
   );
@@ -84,6 +150,16 @@ export function getConfigDecoder(): FixedSizeDecoder<Config> {
     ['feeBps', getU16Decoder()],
     ['paused', getBooleanDecoder()],
     ['bump', getU8Decoder()],
+    ['pendingAdmin', getAddressDecoder()],
+    ['feeRecipient', getAddressDecoder()],
+    ['maxReferralBps', getU16Decoder()],
+    ['surplusShareBps', getU16Decoder()],
+    ['oracleProgram', getAddressDecoder()],
+    ['maxPriceDeviationBps', getU16Decoder()],
+    ['maxOracleStalenessSlots', getU64Decoder()],
+    ['feeSide', getFeeSideDecoder()],
+    ['requireOracle', getBooleanDecoder()],
+    ['pausedDexes', getU32Decoder()],
   ]);
 }
 
@@ -145,5 +221,5 @@ export async function fetchAllMaybeConfig(
 }
 
 export function getConfigSize(): number {
-  return 44;
+  return 160;
 }
@@ -6,4 +6,7 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './adapterRegistry';
 export * from './config';
+export * from './feeTier';
+export * from './referrer';
@@ -34,7 +34,7 @@ export const AGGREGATOR_ERROR__UNAUTHORIZED = 0x1777; // 6007
 export const AGGREGATOR_ERROR__MINT_MISMATCH = 0x1778; // 6008
 /** NoLegs: Route must contain at least one leg */
 export const AGGREGATOR_ERROR__NO_LEGS = 0x1779; // 6009
-/** FeeVaultOwnerMismatch: Fee vault owner does not match admin */
+/** FeeVaultOwnerMismatch: Fee vault is not owned by the fee authority PDA */
 export const AGGREGATOR_ERROR__FEE_VAULT_OWNER_MISMATCH = 0x177a; // 6010
 /** InvalidFeeBps: Invalid fee basis points (must be 0-10000) */
 export const AGGREGATOR_ERROR__INVALID_FEE_BPS = 0x177b; // 6011
@@ -42,40 +42,176 @@ export const AGGREGATOR_ERROR__INVALID_FEE_BPS = 0x177b; // 6011
 export const AGGREGATOR_ERROR__TOO_MANY_LEGS = 0x177c; // 6012
 /** NumericalOverflow: Numerical overflow */
 export const AGGREGATOR_ERROR__NUMERICAL_OVERFLOW = 0x177d; // 6013
+/** ExactOutPlanMismatch: Exact-out legs do not cover the requested output */
+export const AGGREGATOR_ERROR__EXACT_OUT_PLAN_MISMATCH = 0x177e; // 6014
+/** TooManyBranches: Too many branches in split route */
+export const AGGREGATOR_ERROR__TOO_MANY_BRANCHES = 0x177f; // 6015
+/** InvalidInstructionData: Swap instruction data is malformed for the selected adapter */
+export const AGGREGATOR_ERROR__INVALID_INSTRUCTION_DATA = 0x1780; // 6016
+/** InvalidTokenAccount: Account is not a valid token account */
+export const AGGREGATOR_ERROR__INVALID_TOKEN_ACCOUNT = 0x1781; // 6017
+/** LegSlippageExceeded: A swap leg delivered less than its min_out */
+export const AGGREGATOR_ERROR__LEG_SLIPPAGE_EXCEEDED = 0x1782; // 6018
+/** NotNativeMint: Token account is not a wrapped SOL account */
+export const AGGREGATOR_ERROR__NOT_NATIVE_MINT = 0x1783; // 6019
+/** MissingOptionalAccount: An optional account required by the selected route options is missing */
+export const AGGREGATOR_ERROR__MISSING_OPTIONAL_ACCOUNT = 0x1784; // 6020
+/** AdapterDisabled: Adapter is disabled in the registry */
+export const AGGREGATOR_ERROR__ADAPTER_DISABLED = 0x1785; // 6021
+/** RegistryFull: Adapter registry is full */
+export const AGGREGATOR_ERROR__REGISTRY_FULL = 0x1786; // 6022
+/** TooManyAllowedOwners: Too many allowed owners for adapter */
+export const AGGREGATOR_ERROR__TOO_MANY_ALLOWED_OWNERS = 0x1787; // 6023
+/** NoPendingAdmin: No admin transfer is pending */
+export const AGGREGATOR_ERROR__NO_PENDING_ADMIN = 0x1788; // 6024
+/** InvalidConfigAccount: Config account is not the expected PDA */
+export const AGGREGATOR_ERROR__INVALID_CONFIG_ACCOUNT = 0x1789; // 6025
+/** InvalidFeeVault: Token account is not a program-owned fee vault */
+export const AGGREGATOR_ERROR__INVALID_FEE_VAULT = 0x178a; // 6026
+/** FeeDestinationMismatch: Fee withdrawal destination is not owned by the fee recipient */
+export const AGGREGATOR_ERROR__FEE_DESTINATION_MISMATCH = 0x178b; // 6027
+/** ReferralBpsTooHigh: Referral share exceeds the configured maximum */
+export const AGGREGATOR_ERROR__REFERRAL_BPS_TOO_HIGH = 0x178c; // 6028
+/** FeeTierMismatch: Fee tier does not apply to this route's mints */
+export const AGGREGATOR_ERROR__FEE_TIER_MISMATCH = 0x178d; // 6029
+/** InvalidSurplusShareBps: Surplus share exceeds the maximum */
+export const AGGREGATOR_ERROR__INVALID_SURPLUS_SHARE_BPS = 0x178e; // 6030
+/** Expired: Route submitted after its deadline */
+export const AGGREGATOR_ERROR__EXPIRED = 0x178f; // 6031
+/** InvalidPriceFeed: Price feed account is invalid for this mint */
+export const AGGREGATOR_ERROR__INVALID_PRICE_FEED = 0x1790; // 6032
+/** StalePrice: Price feed is stale */
+export const AGGREGATOR_ERROR__STALE_PRICE = 0x1791; // 6033
+/** PriceDeviationExceeded: Route rate deviates too far from the oracle price */
+export const AGGREGATOR_ERROR__PRICE_DEVIATION_EXCEEDED = 0x1792; // 6034
+/** DisallowedInstruction: Instruction is not a whitelisted swap for this adapter */
+export const AGGREGATOR_ERROR__DISALLOWED_INSTRUCTION = 0x1793; // 6035
+/** SwapParamsMismatch: Swap params do not match the leg's DEX, in_amount or min_out */
+export const AGGREGATOR_ERROR__SWAP_PARAMS_MISMATCH = 0x1794; // 6036
+/** QuoteBelowMinOut: Quoted output is below the output the route's minimum requires */
+export const AGGREGATOR_ERROR__QUOTE_BELOW_MIN_OUT = 0x1795; // 6037
+/** UnregisteredReferrer: Referral share must go to a registered referrer */
+export const AGGREGATOR_ERROR__UNREGISTERED_REFERRER = 0x1796; // 6038
+/** SelfReferral: The user cannot be paid its own referral share */
+export const AGGREGATOR_ERROR__SELF_REFERRAL = 0x1797; // 6039
+/** OracleRequired: Price feeds are required for every route */
+export const AGGREGATOR_ERROR__ORACLE_REQUIRED = 0x1798; // 6040
+/** InvalidWsolAccount: Temporary WSOL account is not the expected PDA */
+export const AGGREGATOR_ERROR__INVALID_WSOL_ACCOUNT = 0x1799; // 6041
+/** WrapExceedsMaxIn: wrap_lamports exceeds user_max_in */
+export const AGGREGATOR_ERROR__WRAP_EXCEEDS_MAX_IN = 0x179a; // 6042
+/** LegInputMismatch: A leg does not spend from user_source or the previous leg's output account */
+export const AGGREGATOR_ERROR__LEG_INPUT_MISMATCH = 0x179b; // 6043
+/** LegOutputMismatch: A branch does not deliver to user_destination */
+export const AGGREGATOR_ERROR__LEG_OUTPUT_MISMATCH = 0x179c; // 6044
+/** DexPaused: DEX is paused */
+export const AGGREGATOR_ERROR__DEX_PAUSED = 0x179d; // 6045
+/** ZeroOracleAmount: Oracle-guarded route spent or received nothing */
+export const AGGREGATOR_ERROR__ZERO_ORACLE_AMOUNT = 0x179e; // 6046
+/** InvalidPriceDeviationBps: Max price deviation must be at most 10000 bps */
+export const AGGREGATOR_ERROR__INVALID_PRICE_DEVIATION_BPS = 0x179f; // 6047
 
 export type AggregatorError =
+  | typeof AGGREGATOR_ERROR__ADAPTER_DISABLED
+  | typeof AGGREGATOR_ERROR__DEX_PAUSED
+  | typeof AGGREGATOR_ERROR__DISALLOWED_INSTRUCTION
+  | typeof AGGREGATOR_ERROR__EXACT_OUT_PLAN_MISMATCH
+  | typeof AGGREGATOR_ERROR__EXPIRED
+  | typeof AGGREGATOR_ERROR__FEE_DESTINATION_MISMATCH
+  | typeof AGGREGATOR_ERROR__FEE_TIER_MISMATCH
   | typeof AGGREGATOR_ERROR__FEE_VAULT_MINT_MISMATCH
   | typeof AGGREGATOR_ERROR__FEE_VAULT_OWNER_MISMATCH
+  | typeof AGGREGATOR_ERROR__INVALID_CONFIG_ACCOUNT
   | typeof AGGREGATOR_ERROR__INVALID_FEE_BPS
+  | typeof AGGREGATOR_ERROR__INVALID_FEE_VAULT
+  | typeof AGGREGATOR_ERROR__INVALID_INSTRUCTION_DATA
+  | typeof AGGREGATOR_ERROR__INVALID_PRICE_DEVIATION_BPS
+  | typeof AGGREGATOR_ERROR__INVALID_PRICE_FEED
   | typeof AGGREGATOR_ERROR__INVALID_PROGRAM_ID
+  | typeof AGGREGATOR_ERROR__INVALID_SURPLUS_SHARE_BPS
+  | typeof AGGREGATOR_ERROR__INVALID_TOKEN_ACCOUNT
+  | typeof AGGREGATOR_ERROR__INVALID_WSOL_ACCOUNT
+  | typeof AGGREGATOR_ERROR__LEG_INPUT_MISMATCH
+  | typeof AGGREGATOR_ERROR__LEG_OUTPUT_MISMATCH
+  | typeof AGGREGATOR_ERROR__LEG_SLIPPAGE_EXCEEDED
   | typeof AGGREGATOR_ERROR__MINT_MISMATCH
+  | typeof AGGREGATOR_ERROR__MISSING_OPTIONAL_ACCOUNT
+  | typeof AGGREGATOR_ERROR__NOT_NATIVE_MINT
   | typeof AGGREGATOR_ERROR__NO_LEGS
+  | typeof AGGREGATOR_ERROR__NO_PENDING_ADMIN
   | typeof AGGREGATOR_ERROR__NUMERICAL_OVERFLOW
+  | typeof AGGREGATOR_ERROR__ORACLE_REQUIRED
   | typeof AGGREGATOR_ERROR__PAUSED
+  | typeof AGGREGATOR_ERROR__PRICE_DEVIATION_EXCEEDED
+  | typeof AGGREGATOR_ERROR__QUOTE_BELOW_MIN_OUT
+  | typeof AGGREGATOR_ERROR__REFERRAL_BPS_TOO_HIGH
+  | typeof AGGREGATOR_ERROR__REGISTRY_FULL
   | typeof AGGREGATOR_ERROR__REMAINING_ACCOUNTS_MISMATCH
+  | typeof AGGREGATOR_ERROR__SELF_REFERRAL
   | typeof AGGREGATOR_ERROR__SLIPPAGE_EXCEEDED
+  | typeof AGGREGATOR_ERROR__STALE_PRICE
+  | typeof AGGREGATOR_ERROR__SWAP_PARAMS_MISMATCH
+  | typeof AGGREGATOR_ERROR__TOO_MANY_ALLOWED_OWNERS
+  | typeof AGGREGATOR_ERROR__TOO_MANY_BRANCHES
   | typeof AGGREGATOR_ERROR__TOO_MANY_LEGS
   | typeof AGGREGATOR_ERROR__TOO_MANY_TOKENS_SPENT
   | typeof AGGREGATOR_ERROR__UNAUTHORIZED
-  | typeof AGGREGATOR_ERROR__UNKNOWN_DEX;
+  | typeof AGGREGATOR_ERROR__UNKNOWN_DEX
+  | typeof AGGREGATOR_ERROR__UNREGISTERED_REFERRER
+  | typeof AGGREGATOR_ERROR__WRAP_EXCEEDS_MAX_IN
+  | typeof AGGREGATOR_ERROR__ZERO_ORACLE_AMOUNT;
 
 let aggregatorErrorMessages: Record<AggregatorError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
   aggregatorErrorMessages = {
+    [AGGREGATOR_ERROR__ADAPTER_DISABLED]: `Adapter is disabled in the registry`,
+    [AGGREGATOR_ERROR__DEX_PAUSED]: `DEX is paused`,
+    [AGGREGATOR_ERROR__DISALLOWED_INSTRUCTION]: `Instruction is not a whitelisted swap for this adapter`,
+    [AGGREGATOR_ERROR__EXACT_OUT_PLAN_MISMATCH]: `Exact-out legs do not cover the requested output`,
+    [AGGREGATOR_ERROR__EXPIRED]: `Route submitted after its deadline`,
+    [AGGREGATOR_ERROR__FEE_DESTINATION_MISMATCH]: `Fee withdrawal destination is not owned by the fee recipient`,
+    [AGGREGATOR_ERROR__FEE_TIER_MISMATCH]: `Fee tier does not apply to this route's mints`,
     [AGGREGATOR_ERROR__FEE_VAULT_MINT_MISMATCH]: `Fee vault mint does not match output mint`,
-    [AGGREGATOR_ERROR__FEE_VAULT_OWNER_MISMATCH]: `Fee vault owner does not match admin`,
+    [AGGREGATOR_ERROR__FEE_VAULT_OWNER_MISMATCH]: `Fee vault is not owned by the fee authority PDA`,
+    [AGGREGATOR_ERROR__INVALID_CONFIG_ACCOUNT]: `Config account is not the expected PDA`,
     [AGGREGATOR_ERROR__INVALID_FEE_BPS]: `Invalid fee basis points (must be 0-10000)`,
+    [AGGREGATOR_ERROR__INVALID_FEE_VAULT]: `Token account is not a program-owned fee vault`,
+    [AGGREGATOR_ERROR__INVALID_INSTRUCTION_DATA]: `Swap instruction data is malformed for the selected adapter`,
+    [AGGREGATOR_ERROR__INVALID_PRICE_DEVIATION_BPS]: `Max price deviation must be at most 10000 bps`,
+    [AGGREGATOR_ERROR__INVALID_PRICE_FEED]: `Price feed account is invalid for this mint`,
     [AGGREGATOR_ERROR__INVALID_PROGRAM_ID]: `Invalid program id`,
+    [AGGREGATOR_ERROR__INVALID_SURPLUS_SHARE_BPS]: `Surplus share exceeds the maximum`,
+    [AGGREGATOR_ERROR__INVALID_TOKEN_ACCOUNT]: `Account is not a valid token account`,
+    [AGGREGATOR_ERROR__INVALID_WSOL_ACCOUNT]: `Temporary WSOL account is not the expected PDA`,
+    [AGGREGATOR_ERROR__LEG_INPUT_MISMATCH]: `A leg does not spend from user_source or the previous leg's output account`,
+    [AGGREGATOR_ERROR__LEG_OUTPUT_MISMATCH]: `A branch does not deliver to user_destination`,
+    [AGGREGATOR_ERROR__LEG_SLIPPAGE_EXCEEDED]: `A swap leg delivered less than its min_out`,
     [AGGREGATOR_ERROR__MINT_MISMATCH]: `Mint continuity mismatch between swap legs or accounts`,
+    [AGGREGATOR_ERROR__MISSING_OPTIONAL_ACCOUNT]: `An optional account required by the selected route options is missing`,
+    [AGGREGATOR_ERROR__NOT_NATIVE_MINT]: `Token account is not a wrapped SOL account`,
     [AGGREGATOR_ERROR__NO_LEGS]: `Route must contain at least one leg`,
+    [AGGREGATOR_ERROR__NO_PENDING_ADMIN]: `No admin transfer is pending`,
     [AGGREGATOR_ERROR__NUMERICAL_OVERFLOW]: `Numerical overflow`,
+    [AGGREGATOR_ERROR__ORACLE_REQUIRED]: `Price feeds are required for every route`,
     [AGGREGATOR_ERROR__PAUSED]: `Protocol is paused`,
+    [AGGREGATOR_ERROR__PRICE_DEVIATION_EXCEEDED]: `Route rate deviates too far from the oracle price`,
+    [AGGREGATOR_ERROR__QUOTE_BELOW_MIN_OUT]: `Quoted output is below the output the route's minimum requires`,
+    [AGGREGATOR_ERROR__REFERRAL_BPS_TOO_HIGH]: `Referral share exceeds the configured maximum`,
+    [AGGREGATOR_ERROR__REGISTRY_FULL]: `Adapter registry is full`,
     [AGGREGATOR_ERROR__REMAINING_ACCOUNTS_MISMATCH]: `Insufficient remaining accounts for adapter`,
+    [AGGREGATOR_ERROR__SELF_REFERRAL]: `The user cannot be paid its own referral share`,
     [AGGREGATOR_ERROR__SLIPPAGE_EXCEEDED]: `Not enough output (slippage)`,
+    [AGGREGATOR_ERROR__STALE_PRICE]: `Price feed is stale`,
+    [AGGREGATOR_ERROR__SWAP_PARAMS_MISMATCH]: `Swap params do not match the leg's DEX, in_amount or min_out`,
+    [AGGREGATOR_ERROR__TOO_MANY_ALLOWED_OWNERS]: `Too many allowed owners for adapter`,
+    [AGGREGATOR_ERROR__TOO_MANY_BRANCHES]: `Too many branches in split route`,
     [AGGREGATOR_ERROR__TOO_MANY_LEGS]: `Too many legs in route`,
     [AGGREGATOR_ERROR__TOO_MANY_TOKENS_SPENT]: `Too many tokens spent vs user_max_in`,
     [AGGREGATOR_ERROR__UNAUTHORIZED]: `Unauthorized: admin signature required`,
     [AGGREGATOR_ERROR__UNKNOWN_DEX]: `Unknown DEX id`,
+    [AGGREGATOR_ERROR__UNREGISTERED_REFERRER]: `Referral share must go to a registered referrer`,
+    [AGGREGATOR_ERROR__WRAP_EXCEEDS_MAX_IN]: `wrap_lamports exceeds user_max_in`,
+    [AGGREGATOR_ERROR__ZERO_ORACLE_AMOUNT]: `Oracle-guarded route spent or received nothing`,
   };
 }
 
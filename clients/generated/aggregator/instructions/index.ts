@@ -6,8 +6,28 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './acceptAdmin';
+export * from './closeFeeTier';
+export * from './createFeeTier';
+export * from './initAdapterRegistry';
 export * from './initConfig';
+export * from './migrateConfig';
 export * from './pause';
+export * from './pauseDex';
+export * from './proposeAdmin';
+export * from './registerReferrer';
+export * from './removeReferrer';
 export * from './route';
+export * from './routeExactOut';
+export * from './routeSplit';
+export * from './setAdapter';
 export * from './setConfig';
+export * from './setFeeRecipient';
+export * from './setFeeSide';
+export * from './setMaxReferralBps';
+export * from './setOracleConfig';
+export * from './setSurplusShareBps';
 export * from './unpause';
+export * from './unpauseDex';
+export * from './updateFeeTier';
+export * from './withdrawFees';
@@ -10,6 +10,7 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
   getBytesDecoder,
@@ -30,16 +31,24 @@ import {
   type InstructionWithAccounts,
   type InstructionWithData,
   type ReadonlyAccount,
-  type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/kit';
 import { AGGREGATOR_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+import {
+  getRouteOptionsDecoder,
+  getRouteOptionsEncoder,
   getSwapLegDecoder,
   getSwapLegEncoder,
+  type RouteOptions,
+  type RouteOptionsArgs,
   type SwapLeg,
   type SwapLegArgs,
 } from '../types';
@@ -57,18 +66,34 @@ export type RouteInstruction<
   TAccountUserAuthority extends string | AccountMeta<string> = string,
   TAccountUserSource extends string | AccountMeta<string> = string,
   TAccountUserDestination extends string | AccountMeta<string> = string,
+  TAccountPayer extends string | AccountMeta<string> = string,
+  TAccountDestinationMint extends string | AccountMeta<string> = string,
+  TAccountFeeMint extends string | AccountMeta<string> = string,
+  TAccountFeeTokenProgram extends string | AccountMeta<string> = string,
   TAccountFeeVault extends string | AccountMeta<string> = string,
+  TAccountFeeAuthority extends string | AccountMeta<string> = string,
+  TAccountFeeTier extends string | AccountMeta<string> = string,
+  TAccountDefaultFeeTier extends string | AccountMeta<string> = string,
+  TAccountReferrerTokenAccount extends string | AccountMeta<string> = string,
+  TAccountReferrer extends string | AccountMeta<string> = string,
+  TAccountSourceMint extends string | AccountMeta<string> = string,
+  TAccountSourcePriceFeed extends string | AccountMeta<string> = string,
+  TAccountDestinationPriceFeed extends string | AccountMeta<string> = string,
   TAccountConfig extends string | AccountMeta<string> = string,
-  TAccountTokenProgram extends
+  TAccountAdapterRegistry extends string | AccountMeta<string> = string,
+  TAccountTokenProgram extends string | AccountMeta<string> = string,
+  TAccountSourceTokenProgram extends string | AccountMeta<string> = string,
+  TAccountWsolOut extends string | AccountMeta<string> = string,
+  TAccountSystemProgram extends
     | string
-    | AccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+    | AccountMeta<string> = '11111111111111111111111111111111',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
   InstructionWithAccounts<
     [
       TAccountUserAuthority extends string
-        ? ReadonlySignerAccount<TAccountUserAuthority> &
+        ? WritableSignerAccount<TAccountUserAuthority> &
             AccountSignerMeta<TAccountUserAuthority>
         : TAccountUserAuthority,
       TAccountUserSource extends string
@@ -77,15 +102,64 @@ export type RouteInstruction<
       TAccountUserDestination extends string
         ? WritableAccount<TAccountUserDestination>
         : TAccountUserDestination,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            AccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountDestinationMint extends string
+        ? ReadonlyAccount<TAccountDestinationMint>
+        : TAccountDestinationMint,
+      TAccountFeeMint extends string
+        ? ReadonlyAccount<TAccountFeeMint>
+        : TAccountFeeMint,
+      TAccountFeeTokenProgram extends string
+        ? ReadonlyAccount<TAccountFeeTokenProgram>
+        : TAccountFeeTokenProgram,
       TAccountFeeVault extends string
         ? WritableAccount<TAccountFeeVault>
         : TAccountFeeVault,
+      TAccountFeeAuthority extends string
+        ? ReadonlyAccount<TAccountFeeAuthority>
+        : TAccountFeeAuthority,
+      TAccountFeeTier extends string
+        ? ReadonlyAccount<TAccountFeeTier>
+        : TAccountFeeTier,
+      TAccountDefaultFeeTier extends string
+        ? ReadonlyAccount<TAccountDefaultFeeTier>
+        : TAccountDefaultFeeTier,
+      TAccountReferrerTokenAccount extends string
+        ? WritableAccount<TAccountReferrerTokenAccount>
+        : TAccountReferrerTokenAccount,
+      TAccountReferrer extends string
+        ? ReadonlyAccount<TAccountReferrer>
+        : TAccountReferrer,
+      TAccountSourceMint extends string
+        ? ReadonlyAccount<TAccountSourceMint>
+        : TAccountSourceMint,
+      TAccountSourcePriceFeed extends string
+        ? ReadonlyAccount<TAccountSourcePriceFeed>
+        : TAccountSourcePriceFeed,
+      TAccountDestinationPriceFeed extends string
+        ? ReadonlyAccount<TAccountDestinationPriceFeed>
+        : TAccountDestinationPriceFeed,
       TAccountConfig extends string
         ? ReadonlyAccount<TAccountConfig>
         : TAccountConfig,
+      TAccountAdapterRegistry extends string
+        ? ReadonlyAccount<TAccountAdapterRegistry>
+        : TAccountAdapterRegistry,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountSourceTokenProgram extends string
+        ? ReadonlyAccount<TAccountSourceTokenProgram>
+        : TAccountSourceTokenProgram,
+      TAccountWsolOut extends string
+        ? WritableAccount<TAccountWsolOut>
+        : TAccountWsolOut,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -95,12 +169,14 @@ export type RouteInstructionData = {
   legs: Array<SwapLeg>;
   userMaxIn: bigint;
   userMinOut: bigint;
+  options: RouteOptions;
 };
 
 export type RouteInstructionDataArgs = {
   legs: Array<SwapLegArgs>;
   userMaxIn: number | bigint;
   userMinOut: number | bigint;
+  options: RouteOptionsArgs;
 };
 
 export function getRouteInstructionDataEncoder(): Encoder<RouteInstructionDataArgs> {
@@ -110,6 +186,7 @@ export function getRouteInstructionDataEncoder(): Encoder<RouteInstructionDataAr
       ['legs', getArrayEncoder(getSwapLegEncoder())],
       ['userMaxIn', getU64Encoder()],
       ['userMinOut', getU64Encoder()],
+      ['options', getRouteOptionsEncoder()],
     ]),
     (value) => ({ ...value, discriminator: ROUTE_DISCRIMINATOR })
   );
@@ -121,6 +198,7 @@ export function getRouteInstructionDataDecoder(): Decoder<RouteInstructionData>
     ['legs', getArrayDecoder(getSwapLegDecoder())],
     ['userMaxIn', getU64Decoder()],
     ['userMinOut', getU64Decoder()],
+    ['options', getRouteOptionsDecoder()],
   ]);
 }
 
@@ -138,41 +216,143 @@ export type RouteAsyncInput<
   TAccountUserAuthority extends string = string,
   TAccountUserSource extends string = string,
   TAccountUserDestination extends string = string,
+  TAccountPayer extends string = string,
+  TAccountDestinationMint extends string = string,
+  TAccountFeeMint extends string = string,
+  TAccountFeeTokenProgram extends string = string,
   TAccountFeeVault extends string = string,
+  TAccountFeeAuthority extends string = string,
+  TAccountFeeTier extends string = string,
+  TAccountDefaultFeeTier extends string = string,
+  TAccountReferrerTokenAccount extends string = string,
+  TAccountReferrer extends string = string,
+  TAccountSourceMint extends string = string,
+  TAccountSourcePriceFeed extends string = string,
+  TAccountDestinationPriceFeed extends string = string,
   TAccountConfig extends string = string,
+  TAccountAdapterRegistry extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountSourceTokenProgram extends string = string,
+  TAccountWsolOut extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
-  /** User */
+  /** User; writable so it can fund SOL wraps and receive unwrapped lamports. */
   userAuthority: TransactionSigner<TAccountUserAuthority>;
+  /**
+   * legs carry whichever token program their pools need.  With
+   * `options.wrap_lamports` this is the `[b"wsol_in", user_authority]` PDA the
+   * route creates and closes; read and checked by `router::execute`.
+   */
   userSource: Address<TAccountUserSource>;
   userDestination: Address<TAccountUserDestination>;
-  /** Fee collector */
-  feeVault: Address<TAccountFeeVault>;
+  /**
+   * Pays rent for the fee vault the first time a fee mint is charged; may
+   * be the user or a separate rent sponsor.
+   */
+  payer: TransactionSigner<TAccountPayer>;
+  /** Output mint; read for its Token-2022 transfer fee configuration. */
+  destinationMint: Address<TAccountDestinationMint>;
+  /**
+   * Mint the fee is charged in: the output mint, or the input mint when
+   * `cfg.fee_side` is `FeeSide::Input`.
+   */
+  feeMint: Address<TAccountFeeMint>;
+  /** Token program owning `fee_mint`. */
+  feeTokenProgram: Address<TAccountFeeTokenProgram>;
+  /** Program-owned fee vault for `fee_mint`, created on first use. */
+  feeVault?: Address<TAccountFeeVault>;
+  feeAuthority?: Address<TAccountFeeAuthority>;
+  /** exists; address and contents are checked by `router::fee_bps`. */
+  feeTier: Address<TAccountFeeTier>;
+  /** used when `fee_tier` does not exist; checked by `router::fee_bps`. */
+  defaultFeeTier: Address<TAccountDefaultFeeTier>;
+  /**
+   * Integrator's token account for `fee_mint`; receives
+   * `options.referral_bps` of the fee.
+   */
+  referrerTokenAccount?: Address<TAccountReferrerTokenAccount>;
+  /**
+   * Registration of `referrer_token_account`'s owner; required with a
+   * referral share.
+   */
+  referrer?: Address<TAccountReferrer>;
+  /**
+   * Input mint; only needed for the oracle guard and to wrap SOL, and
+   * checked against `user_source` by `router::execute`.
+   */
+  sourceMint?: Address<TAccountSourceMint>;
+  /** oracle guard (mandatory with `cfg.require_oracle`). */
+  sourcePriceFeed?: Address<TAccountSourcePriceFeed>;
+  destinationPriceFeed?: Address<TAccountDestinationPriceFeed>;
   /** Global protocol config */
   config?: Address<TAccountConfig>;
-  /** Programs */
-  tokenProgram?: Address<TAccountTokenProgram>;
+  /** DEX program IDs and owner whitelists for this cluster */
+  adapterRegistry?: Address<TAccountAdapterRegistry>;
+  /** Token program owning the output mint (SPL Token or Token-2022). */
+  tokenProgram: Address<TAccountTokenProgram>;
+  /** Token program owning `user_source`; only needed to wrap SOL. */
+  sourceTokenProgram?: Address<TAccountSourceTokenProgram>;
+  /**
+   * `options.unwrap_sol` pays the output through; created and closed by
+   * `router::unwrap_sol`.
+   */
+  wsolOut?: Address<TAccountWsolOut>;
+  /** Creates fee vaults and the temporary WSOL accounts. */
+  systemProgram?: Address<TAccountSystemProgram>;
   legs: RouteInstructionDataArgs['legs'];
   userMaxIn: RouteInstructionDataArgs['userMaxIn'];
   userMinOut: RouteInstructionDataArgs['userMinOut'];
+  options: RouteInstructionDataArgs['options'];
 };
 
 export async function getRouteInstructionAsync<
   TAccountUserAuthority extends string,
   TAccountUserSource extends string,
   TAccountUserDestination extends string,
+  TAccountPayer extends string,
+  TAccountDestinationMint extends string,
+  TAccountFeeMint extends string,
+  TAccountFeeTokenProgram extends string,
   TAccountFeeVault extends string,
+  TAccountFeeAuthority extends string,
+  TAccountFeeTier extends string,
+  TAccountDefaultFeeTier extends string,
+  TAccountReferrerTokenAccount extends string,
+  TAccountReferrer extends string,
+  TAccountSourceMint extends string,
+  TAccountSourcePriceFeed extends string,
+  TAccountDestinationPriceFeed extends string,
   TAccountConfig extends string,
+  TAccountAdapterRegistry extends string,
   TAccountTokenProgram extends string,
+  TAccountSourceTokenProgram extends string,
+  TAccountWsolOut extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof AGGREGATOR_PROGRAM_ADDRESS,
 >(
   input: RouteAsyncInput<
     TAccountUserAuthority,
     TAccountUserSource,
     TAccountUserDestination,
+    TAccountPayer,
+    TAccountDestinationMint,
+    TAccountFeeMint,
+    TAccountFeeTokenProgram,
     TAccountFeeVault,
+    TAccountFeeAuthority,
+    TAccountFeeTier,
+    TAccountDefaultFeeTier,
+    TAccountReferrerTokenAccount,
+    TAccountReferrer,
+    TAccountSourceMint,
+    TAccountSourcePriceFeed,
+    TAccountDestinationPriceFeed,
     TAccountConfig,
-    TAccountTokenProgram
+    TAccountAdapterRegistry,
+    TAccountTokenProgram,
+    TAccountSourceTokenProgram,
+    TAccountWsolOut,
+    TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -181,9 +361,25 @@ export async function getRouteInstructionAsync<
     TAccountUserAuthority,
     TAccountUserSource,
     TAccountUserDestination,
+    TAccountPayer,
+    TAccountDestinationMint,
+    TAccountFeeMint,
+    TAccountFeeTokenProgram,
     TAccountFeeVault,
+    TAccountFeeAuthority,
+    TAccountFeeTier,
+    TAccountDefaultFeeTier,
+    TAccountReferrerTokenAccount,
+    TAccountReferrer,
+    TAccountSourceMint,
+    TAccountSourcePriceFeed,
+    TAccountDestinationPriceFeed,
     TAccountConfig,
-    TAccountTokenProgram
+    TAccountAdapterRegistry,
+    TAccountTokenProgram,
+    TAccountSourceTokenProgram,
+    TAccountWsolOut,
+    TAccountSystemProgram
   >
 > {
   // Program address.
@@ -191,12 +387,49 @@ export async function getRouteInstructionAsync<
 
   // Original accounts.
   const originalAccounts = {
-    userAuthority: { value: input.userAuthority ?? null, isWritable: false },
+    userAuthority: { value: input.userAuthority ?? null, isWritable: true },
     userSource: { value: input.userSource ?? null, isWritable: true },
     userDestination: { value: input.userDestination ?? null, isWritable: true },
+    payer: { value: input.payer ?? null, isWritable: true },
+    destinationMint: {
+      value: input.destinationMint ?? null,
+      isWritable: false,
+    },
+    feeMint: { value: input.feeMint ?? null, isWritable: false },
+    feeTokenProgram: {
+      value: input.feeTokenProgram ?? null,
+      isWritable: false,
+    },
     feeVault: { value: input.feeVault ?? null, isWritable: true },
+    feeAuthority: { value: input.feeAuthority ?? null, isWritable: false },
+    feeTier: { value: input.feeTier ?? null, isWritable: false },
+    defaultFeeTier: { value: input.defaultFeeTier ?? null, isWritable: false },
+    referrerTokenAccount: {
+      value: input.referrerTokenAccount ?? null,
+      isWritable: true,
+    },
+    referrer: { value: input.referrer ?? null, isWritable: false },
+    sourceMint: { value: input.sourceMint ?? null, isWritable: false },
+    sourcePriceFeed: {
+      value: input.sourcePriceFeed ?? null,
+      isWritable: false,
+    },
+    destinationPriceFeed: {
+      value: input.destinationPriceFeed ?? null,
+      isWritable: false,
+    },
     config: { value: input.config ?? null, isWritable: false },
+    adapterRegistry: {
+      value: input.adapterRegistry ?? null,
+      isWritable: false,
+    },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    sourceTokenProgram: {
+      value: input.sourceTokenProgram ?? null,
+      isWritable: false,
+    },
+    wsolOut: { value: input.wsolOut ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -207,6 +440,29 @@ export async function getRouteInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.feeVault.value) {
+    accounts.feeVault.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([102, 101, 101, 95, 118, 97, 117, 108, 116])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.feeMint.value)),
+      ],
+    });
+  }
+  if (!accounts.feeAuthority.value) {
+    accounts.feeAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            102, 101, 101, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121,
+          ])
+        ),
+      ],
+    });
+  }
   if (!accounts.config.value) {
     accounts.config.value = await getProgramDerivedAddress({
       programAddress,
@@ -215,9 +471,22 @@ export async function getRouteInstructionAsync<
       ],
     });
   }
-  if (!accounts.tokenProgram.value) {
-    accounts.tokenProgram.value =
-      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  if (!accounts.adapterRegistry.value) {
+    accounts.adapterRegistry.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            97, 100, 97, 112, 116, 101, 114, 95, 114, 101, 103, 105, 115, 116,
+            114, 121,
+          ])
+        ),
+      ],
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
@@ -226,9 +495,25 @@ export async function getRouteInstructionAsync<
       getAccountMeta(accounts.userAuthority),
       getAccountMeta(accounts.userSource),
       getAccountMeta(accounts.userDestination),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.destinationMint),
+      getAccountMeta(accounts.feeMint),
+      getAccountMeta(accounts.feeTokenProgram),
       getAccountMeta(accounts.feeVault),
+      getAccountMeta(accounts.feeAuthority),
+      getAccountMeta(accounts.feeTier),
+      getAccountMeta(accounts.defaultFeeTier),
+      getAccountMeta(accounts.referrerTokenAccount),
+      getAccountMeta(accounts.referrer),
+      getAccountMeta(accounts.sourceMint),
+      getAccountMeta(accounts.sourcePriceFeed),
+      getAccountMeta(accounts.destinationPriceFeed),
       getAccountMeta(accounts.config),
+      getAccountMeta(accounts.adapterRegistry),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.sourceTokenProgram),
+      getAccountMeta(accounts.wsolOut),
+      getAccountMeta(accounts.systemProgram),
     ],
     programAddress,
     data: getRouteInstructionDataEncoder().encode(
@@ -239,9 +524,25 @@ export async function getRouteInstructionAsync<
     TAccountUserAuthority,
     TAccountUserSource,
     TAccountUserDestination,
+    TAccountPayer,
+    TAccountDestinationMint,
+    TAccountFeeMint,
+    TAccountFeeTokenProgram,
     TAccountFeeVault,
+    TAccountFeeAuthority,
+    TAccountFeeTier,
+    TAccountDefaultFeeTier,
+    TAccountReferrerTokenAccount,
+    TAccountReferrer,
+    TAccountSourceMint,
+    TAccountSourcePriceFeed,
+    TAccountDestinationPriceFeed,
     TAccountConfig,
-    TAccountTokenProgram
+    TAccountAdapterRegistry,
+    TAccountTokenProgram,
+    TAccountSourceTokenProgram,
+    TAccountWsolOut,
+    TAccountSystemProgram
   >;
 
   return instruction;
@@ -251,41 +552,143 @@ export type RouteInput<
   TAccountUserAuthority extends string = string,
   TAccountUserSource extends string = string,
   TAccountUserDestination extends string = string,
+  TAccountPayer extends string = string,
+  TAccountDestinationMint extends string = string,
+  TAccountFeeMint extends string = string,
+  TAccountFeeTokenProgram extends string = string,
   TAccountFeeVault extends string = string,
+  TAccountFeeAuthority extends string = string,
+  TAccountFeeTier extends string = string,
+  TAccountDefaultFeeTier extends string = string,
+  TAccountReferrerTokenAccount extends string = string,
+  TAccountReferrer extends string = string,
+  TAccountSourceMint extends string = string,
+  TAccountSourcePriceFeed extends string = string,
+  TAccountDestinationPriceFeed extends string = string,
   TAccountConfig extends string = string,
+  TAccountAdapterRegistry extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountSourceTokenProgram extends string = string,
+  TAccountWsolOut extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
-  /** User */
+  /** User; writable so it can fund SOL wraps and receive unwrapped lamports. */
   userAuthority: TransactionSigner<TAccountUserAuthority>;
+  /**
+   * legs carry whichever token program their pools need.  With
+   * `options.wrap_lamports` this is the `[b"wsol_in", user_authority]` PDA the
+   * route creates and closes; read and checked by `router::execute`.
+   */
   userSource: Address<TAccountUserSource>;
   userDestination: Address<TAccountUserDestination>;
-  /** Fee collector */
+  /**
+   * Pays rent for the fee vault the first time a fee mint is charged; may
+   * be the user or a separate rent sponsor.
+   */
+  payer: TransactionSigner<TAccountPayer>;
+  /** Output mint; read for its Token-2022 transfer fee configuration. */
+  destinationMint: Address<TAccountDestinationMint>;
+  /**
+   * Mint the fee is charged in: the output mint, or the input mint when
+   * `cfg.fee_side` is `FeeSide::Input`.
+   */
+  feeMint: Address<TAccountFeeMint>;
+  /** Token program owning `fee_mint`. */
+  feeTokenProgram: Address<TAccountFeeTokenProgram>;
+  /** Program-owned fee vault for `fee_mint`, created on first use. */
   feeVault: Address<TAccountFeeVault>;
+  feeAuthority: Address<TAccountFeeAuthority>;
+  /** exists; address and contents are checked by `router::fee_bps`. */
+  feeTier: Address<TAccountFeeTier>;
+  /** used when `fee_tier` does not exist; checked by `router::fee_bps`. */
+  defaultFeeTier: Address<TAccountDefaultFeeTier>;
+  /**
+   * Integrator's token account for `fee_mint`; receives
+   * `options.referral_bps` of the fee.
+   */
+  referrerTokenAccount?: Address<TAccountReferrerTokenAccount>;
+  /**
+   * Registration of `referrer_token_account`'s owner; required with a
+   * referral share.
+   */
+  referrer?: Address<TAccountReferrer>;
+  /**
+   * Input mint; only needed for the oracle guard and to wrap SOL, and
+   * checked against `user_source` by `router::execute`.
+   */
+  sourceMint?: Address<TAccountSourceMint>;
+  /** oracle guard (mandatory with `cfg.require_oracle`). */
+  sourcePriceFeed?: Address<TAccountSourcePriceFeed>;
+  destinationPriceFeed?: Address<TAccountDestinationPriceFeed>;
   /** Global protocol config */
   config: Address<TAccountConfig>;
-  /** Programs */
-  tokenProgram?: Address<TAccountTokenProgram>;
+  /** DEX program IDs and owner whitelists for this cluster */
+  adapterRegistry: Address<TAccountAdapterRegistry>;
+  /** Token program owning the output mint (SPL Token or Token-2022). */
+  tokenProgram: Address<TAccountTokenProgram>;
+  /** Token program owning `user_source`; only needed to wrap SOL. */
+  sourceTokenProgram?: Address<TAccountSourceTokenProgram>;
+  /**
+   * `options.unwrap_sol` pays the output through; created and closed by
+   * `router::unwrap_sol`.
+   */
+  wsolOut?: Address<TAccountWsolOut>;
+  /** Creates fee vaults and the temporary WSOL accounts. */
+  systemProgram?: Address<TAccountSystemProgram>;
   legs: RouteInstructionDataArgs['legs'];
   userMaxIn: RouteInstructionDataArgs['userMaxIn'];
   userMinOut: RouteInstructionDataArgs['userMinOut'];
+  options: RouteInstructionDataArgs['options'];
 };
 
 export function getRouteInstruction<
   TAccountUserAuthority extends string,
   TAccountUserSource extends string,
   TAccountUserDestination extends string,
+  TAccountPayer extends string,
+  TAccountDestinationMint extends string,
+  TAccountFeeMint extends string,
+  TAccountFeeTokenProgram extends string,
   TAccountFeeVault extends string,
+  TAccountFeeAuthority extends string,
+  TAccountFeeTier extends string,
+  TAccountDefaultFeeTier extends string,
+  TAccountReferrerTokenAccount extends string,
+  TAccountReferrer extends string,
+  TAccountSourceMint extends string,
+  TAccountSourcePriceFeed extends string,
+  TAccountDestinationPriceFeed extends string,
   TAccountConfig extends string,
+  TAccountAdapterRegistry extends string,
   TAccountTokenProgram extends string,
+  TAccountSourceTokenProgram extends string,
+  TAccountWsolOut extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof AGGREGATOR_PROGRAM_ADDRESS,
 >(
   input: RouteInput<
     TAccountUserAuthority,
     TAccountUserSource,
     TAccountUserDestination,
+    TAccountPayer,
+    TAccountDestinationMint,
+    TAccountFeeMint,
+    TAccountFeeTokenProgram,
     TAccountFeeVault,
+    TAccountFeeAuthority,
+    TAccountFeeTier,
+    TAccountDefaultFeeTier,
+    TAccountReferrerTokenAccount,
+    TAccountReferrer,
+    TAccountSourceMint,
+    TAccountSourcePriceFeed,
+    TAccountDestinationPriceFeed,
     TAccountConfig,
-    TAccountTokenProgram
+    TAccountAdapterRegistry,
+    TAccountTokenProgram,
+    TAccountSourceTokenProgram,
+    TAccountWsolOut,
+    TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): RouteInstruction<
@@ -293,21 +696,74 @@ export function getRouteInstruction<
   TAccountUserAuthority,
   TAccountUserSource,
   TAccountUserDestination,
+  TAccountPayer,
+  TAccountDestinationMint,
+  TAccountFeeMint,
+  TAccountFeeTokenProgram,
   TAccountFeeVault,
+  TAccountFeeAuthority,
+  TAccountFeeTier,
+  TAccountDefaultFeeTier,
+  TAccountReferrerTokenAccount,
+  TAccountReferrer,
+  TAccountSourceMint,
+  TAccountSourcePriceFeed,
+  TAccountDestinationPriceFeed,
   TAccountConfig,
-  TAccountTokenProgram
+  TAccountAdapterRegistry,
+  TAccountTokenProgram,
+  TAccountSourceTokenProgram,
+  TAccountWsolOut,
+  TAccountSystemProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? AGGREGATOR_PROGRAM_ADDRESS;
 
   // Original accounts.
   const originalAccounts = {
-    userAuthority: { value: input.userAuthority ?? null, isWritable: false },
+    userAuthority: { value: input.userAuthority ?? null, isWritable: true },
     userSource: { value: input.userSource ?? null, isWritable: true },
     userDestination: { value: input.userDestination ?? null, isWritable: true },
+    payer: { value: input.payer ?? null, isWritable: true },
+    destinationMint: {
+      value: input.destinationMint ?? null,
+      isWritable: false,
+    },
+    feeMint: { value: input.feeMint ?? null, isWritable: false },
+    feeTokenProgram: {
+      value: input.feeTokenProgram ?? null,
+      isWritable: false,
+    },
     feeVault: { value: input.feeVault ?? null, isWritable: true },
+    feeAuthority: { value: input.feeAuthority ?? null, isWritable: false },
+    feeTier: { value: input.feeTier ?? null, isWritable: false },
+    defaultFeeTier: { value: input.defaultFeeTier ?? null, isWritable: false },
+    referrerTokenAccount: {
+      value: input.referrerTokenAccount ?? null,
+      isWritable: true,
+    },
+    referrer: { value: input.referrer ?? null, isWritable: false },
+    sourceMint: { value: input.sourceMint ?? null, isWritable: false },
+    sourcePriceFeed: {
+      value: input.sourcePriceFeed ?? null,
+      isWritable: false,
+    },
+    destinationPriceFeed: {
+      value: input.destinationPriceFeed ?? null,
+      isWritable: false,
+    },
     config: { value: input.config ?? null, isWritable: false },
+    adapterRegistry: {
+      value: input.adapterRegistry ?? null,
+      isWritable: false,
+    },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    sourceTokenProgram: {
+      value: input.sourceTokenProgram ?? null,
+      isWritable: false,
+    },
+    wsolOut: { value: input.wsolOut ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -318,9 +774,9 @@ export function getRouteInstruction<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.tokenProgram.value) {
-    accounts.tokenProgram.value =
-      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
@@ -329,9 +785,25 @@ export function getRouteInstruction<
       getAccountMeta(accounts.userAuthority),
       getAccountMeta(accounts.userSource),
       getAccountMeta(accounts.userDestination),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.destinationMint),
+      getAccountMeta(accounts.feeMint),
+      getAccountMeta(accounts.feeTokenProgram),
       getAccountMeta(accounts.feeVault),
+      getAccountMeta(accounts.feeAuthority),
+      getAccountMeta(accounts.feeTier),
+      getAccountMeta(accounts.defaultFeeTier),
+      getAccountMeta(accounts.referrerTokenAccount),
+      getAccountMeta(accounts.referrer),
+      getAccountMeta(accounts.sourceMint),
+      getAccountMeta(accounts.sourcePriceFeed),
+      getAccountMeta(accounts.destinationPriceFeed),
       getAccountMeta(accounts.config),
+      getAccountMeta(accounts.adapterRegistry),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.sourceTokenProgram),
+      getAccountMeta(accounts.wsolOut),
+      getAccountMeta(accounts.systemProgram),
     ],
     programAddress,
     data: getRouteInstructionDataEncoder().encode(
@@ -342,9 +814,25 @@ export function getRouteInstruction<
     TAccountUserAuthority,
     TAccountUserSource,
     TAccountUserDestination,
+    TAccountPayer,
+    TAccountDestinationMint,
+    TAccountFeeMint,
+    TAccountFeeTokenProgram,
     TAccountFeeVault,
+    TAccountFeeAuthority,
+    TAccountFeeTier,
+    TAccountDefaultFeeTier,
+    TAccountReferrerTokenAccount,
+    TAccountReferrer,
+    TAccountSourceMint,
+    TAccountSourcePriceFeed,
+    TAccountDestinationPriceFeed,
     TAccountConfig,
-    TAccountTokenProgram
+    TAccountAdapterRegistry,
+    TAccountTokenProgram,
+    TAccountSourceTokenProgram,
+    TAccountWsolOut,
+    TAccountSystemProgram
   >;
 
   return instruction;
@@ -356,16 +844,69 @@ export type ParsedRouteInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    /** User */
+    /** User; writable so it can fund SOL wraps and receive unwrapped lamports. */
     userAuthority: TAccountMetas[0];
+    /**
+     * legs carry whichever token program their pools need.  With
+     * `options.wrap_lamports` this is the `[b"wsol_in", user_authority]` PDA the
+     * route creates and closes; read and checked by `router::execute`.
+     */
     userSource: TAccountMetas[1];
     userDestination: TAccountMetas[2];
-    /** Fee collector */
-    feeVault: TAccountMetas[3];
+    /**
+     * Pays rent for the fee vault the first time a fee mint is charged; may
+     * be the user or a separate rent sponsor.
+     */
+    payer: TAccountMetas[3];
+    /** Output mint; read for its Token-2022 transfer fee configuration. */
+    destinationMint: TAccountMetas[4];
+    /**
+     * Mint the fee is charged in: the output mint, or the input mint when
+     * `cfg.fee_side` is `FeeSide::Input`.
+     */
+    feeMint: TAccountMetas[5];
+    /** Token program owning `fee_mint`. */
+    feeTokenProgram: TAccountMetas[6];
+    /** Program-owned fee vault for `fee_mint`, created on first use. */
+    feeVault: TAccountMetas[7];
+    feeAuthority: TAccountMetas[8];
+    /** exists; address and contents are checked by `router::fee_bps`. */
+    feeTier: TAccountMetas[9];
+    /** used when `fee_tier` does not exist; checked by `router::fee_bps`. */
+    defaultFeeTier: TAccountMetas[10];
+    /**
+     * Integrator's token account for `fee_mint`; receives
+     * `options.referral_bps` of the fee.
+     */
+    referrerTokenAccount?: TAccountMetas[11] | undefined;
+    /**
+     * Registration of `referrer_token_account`'s owner; required with a
+     * referral share.
+     */
+    referrer?: TAccountMetas[12] | undefined;
+    /**
+     * Input mint; only needed for the oracle guard and to wrap SOL, and
+     * checked against `user_source` by `router::execute`.
+     */
+    sourceMint?: TAccountMetas[13] | undefined;
+    /** oracle guard (mandatory with `cfg.require_oracle`). */
+    sourcePriceFeed?: TAccountMetas[14] | undefined;
+    destinationPriceFeed?: TAccountMetas[15] | undefined;
     /** Global protocol config */
-    config: TAccountMetas[4];
-    /** Programs */
-    tokenProgram: TAccountMetas[5];
+    config: TAccountMetas[16];
+    /** DEX program IDs and owner whitelists for this cluster */
+    adapterRegistry: TAccountMetas[17];
+    /** Token program owning the output mint (SPL Token or Token-2022). */
+    tokenProgram: TAccountMetas[18];
+    /** Token program owning `user_source`; only needed to wrap SOL. */
+    sourceTokenProgram?: TAccountMetas[19] | undefined;
+    /**
+     * `options.unwrap_sol` pays the output through; created and closed by
+     * `router::unwrap_sol`.
+     */
+    wsolOut?: TAccountMetas[20] | undefined;
+    /** Creates fee vaults and the temporary WSOL accounts. */
+    systemProgram: TAccountMetas[21];
   };
   data: RouteInstructionData;
 };
@@ -378,7 +919,7 @@ export function parseRouteInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedRouteInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 22) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -388,15 +929,37 @@ export function parseRouteInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === AGGREGATOR_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       userAuthority: getNextAccount(),
       userSource: getNextAccount(),
       userDestination: getNextAccount(),
+      payer: getNextAccount(),
+      destinationMint: getNextAccount(),
+      feeMint: getNextAccount(),
+      feeTokenProgram: getNextAccount(),
       feeVault: getNextAccount(),
+      feeAuthority: getNextAccount(),
+      feeTier: getNextAccount(),
+      defaultFeeTier: getNextAccount(),
+      referrerTokenAccount: getNextOptionalAccount(),
+      referrer: getNextOptionalAccount(),
+      sourceMint: getNextOptionalAccount(),
+      sourcePriceFeed: getNextOptionalAccount(),
+      destinationPriceFeed: getNextOptionalAccount(),
       config: getNextAccount(),
+      adapterRegistry: getNextAccount(),
       tokenProgram: getNextAccount(),
+      sourceTokenProgram: getNextOptionalAccount(),
+      wsolOut: getNextOptionalAccount(),
+      systemProgram: getNextAccount(),
     },
     data: getRouteInstructionDataDecoder().decode(instruction.data),
   };
@@ -14,24 +14,58 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedAcceptAdminInstruction,
+  type ParsedCloseFeeTierInstruction,
+  type ParsedCreateFeeTierInstruction,
+  type ParsedInitAdapterRegistryInstruction,
   type ParsedInitConfigInstruction,
+  type ParsedMigrateConfigInstruction,
+  type ParsedPauseDexInstruction,
   type ParsedPauseInstruction,
+  type ParsedProposeAdminInstruction,
+  type ParsedRegisterReferrerInstruction,
+  type ParsedRemoveReferrerInstruction,
+  type ParsedRouteExactOutInstruction,
   type ParsedRouteInstruction,
+  type ParsedRouteSplitInstruction,
+  type ParsedSetAdapterInstruction,
   type ParsedSetConfigInstruction,
+  type ParsedSetFeeRecipientInstruction,
+  type ParsedSetFeeSideInstruction,
+  type ParsedSetMaxReferralBpsInstruction,
+  type ParsedSetOracleConfigInstruction,
+  type ParsedSetSurplusShareBpsInstruction,
+  type ParsedUnpauseDexInstruction,
   type ParsedUnpauseInstruction,
+  type ParsedUpdateFeeTierInstruction,
+  type ParsedWithdrawFeesInstruction,
 } from '../instructions';
 
 export const AGGREGATOR_PROGRAM_ADDRESS =
   '7XEqP1W4vwMtPfkkgs97RnQSeksJHT1jSFuJvg3zm2Hs' as Address<'7XEqP1W4vwMtPfkkgs97RnQSeksJHT1jSFuJvg3zm2Hs'>;
 
 export enum AggregatorAccount {
+  AdapterRegistry,
   Config,
+  FeeTier,
+  Referrer,
 }
 
 export function identifyAggregatorAccount(
   account: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): AggregatorAccount {
   const data = 'data' in account ? account.data : account;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([27, 187, 195, 109, 0, 66, 232, 31])
+      ),
+      0
+    )
+  ) {
+    return AggregatorAccount.AdapterRegistry;
+  }
   if (
     containsBytes(
       data,
@@ -43,23 +77,109 @@ export function identifyAggregatorAccount(
   ) {
     return AggregatorAccount.Config;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([56, 75, 159, 76, 142, 68, 190, 105])
+      ),
+      0
+    )
+  ) {
+    return AggregatorAccount.FeeTier;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([99, 150, 214, 66, 111, 120, 49, 126])
+      ),
+      0
+    )
+  ) {
+    return AggregatorAccount.Referrer;
+  }
   throw new Error(
     'The provided account could not be identified as a aggregator account.'
   );
 }
 
 export enum AggregatorInstruction {
+  AcceptAdmin,
+  CloseFeeTier,
+  CreateFeeTier,
+  InitAdapterRegistry,
   InitConfig,
+  MigrateConfig,
   Pause,
+  PauseDex,
+  ProposeAdmin,
+  RegisterReferrer,
+  RemoveReferrer,
   Route,
+  RouteExactOut,
+  RouteSplit,
+  SetAdapter,
   SetConfig,
+  SetFeeRecipient,
+  SetFeeSide,
+  SetMaxReferralBps,
+  SetOracleConfig,
+  SetSurplusShareBps,
   Unpause,
+  UnpauseDex,
+  UpdateFeeTier,
+  WithdrawFees,
 }
 
 export function identifyAggregatorInstruction(
   instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): AggregatorInstruction {
   const data = 'data' in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([112, 42, 45, 90, 116, 181, 13, 170])
+      ),
+      0
+    )
+  ) {
+    return AggregatorInstruction.AcceptAdmin;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([148, 73, 249, 218, 103, 187, 189, 213])
+      ),
+      0
+    )
+  ) {
+    return AggregatorInstruction.CloseFeeTier;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([150, 158, 85, 114, 219, 75, 212, 91])
+      ),
+      0
+    )
+  ) {
+    return AggregatorInstruction.CreateFeeTier;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([67, 106, 163, 85, 187, 153, 130, 251])
+      ),
+      0
+    )
+  ) {
+    return AggregatorInstruction.InitAdapterRegistry;
+  }
   if (
     containsBytes(
       data,
@@ -71,6 +191,17 @@ export function identifyAggregatorInstruction(
   ) {
     return AggregatorInstruction.InitConfig;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([92, 131, 58, 105, 210, 154, 224, 193])
+      ),
+      0
+    )
+  ) {
+    return AggregatorInstruction.MigrateConfig;
+  }
   if (
     containsBytes(
       data,
@@ -82,6 +213,50 @@ export function identifyAggregatorInstruction(
   ) {
     return AggregatorInstruction.Pause;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([138, 255, 101, 0, 116, 202, 128, 100])
+      ),
+      0
+    )
+  ) {
+    return AggregatorInstruction.PauseDex;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([121, 214, 199, 212, 87, 39, 117, 234])
+      ),
+      0
+    )
+  ) {
+    return AggregatorInstruction.ProposeAdmin;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([122, 229, 215, 169, 100, 145, 198, 120])
+      ),
+      0
+    )
+  ) {
+    return AggregatorInstruction.RegisterReferrer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([132, 91, 23, 211, 254, 186, 141, 137])
+      ),
+      0
+    )
+  ) {
+    return AggregatorInstruction.RemoveReferrer;
+  }
   if (
     containsBytes(
       data,
@@ -93,6 +268,39 @@ export function identifyAggregatorInstruction(
   ) {
     return AggregatorInstruction.Route;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([43, 5, 26, 208, 142, 84, 128, 151])
+      ),
+      0
+    )
+  ) {
+    return AggregatorInstruction.RouteExactOut;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([191, 88, 37, 31, 187, 140, 203, 40])
+      ),
+      0
+    )
+  ) {
+    return AggregatorInstruction.RouteSplit;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([45, 14, 7, 253, 44, 140, 216, 180])
+      ),
+      0
+    )
+  ) {
+    return AggregatorInstruction.SetAdapter;
+  }
   if (
     containsBytes(
       data,
@@ -104,6 +312,61 @@ export function identifyAggregatorInstruction(
   ) {
     return AggregatorInstruction.SetConfig;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([227, 18, 215, 42, 237, 246, 151, 66])
+      ),
+      0
+    )
+  ) {
+    return AggregatorInstruction.SetFeeRecipient;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([81, 54, 122, 215, 110, 93, 248, 171])
+      ),
+      0
+    )
+  ) {
+    return AggregatorInstruction.SetFeeSide;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([101, 68, 71, 40, 15, 10, 118, 223])
+      ),
+      0
+    )
+  ) {
+    return AggregatorInstruction.SetMaxReferralBps;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([96, 171, 6, 98, 153, 183, 233, 31])
+      ),
+      0
+    )
+  ) {
+    return AggregatorInstruction.SetOracleConfig;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([219, 66, 189, 132, 205, 78, 11, 224])
+      ),
+      0
+    )
+  ) {
+    return AggregatorInstruction.SetSurplusShareBps;
+  }
   if (
     containsBytes(
       data,
@@ -115,6 +378,39 @@ export function identifyAggregatorInstruction(
   ) {
     return AggregatorInstruction.Unpause;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([88, 52, 175, 105, 210, 116, 178, 218])
+      ),
+      0
+    )
+  ) {
+    return AggregatorInstruction.UnpauseDex;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([48, 181, 157, 9, 44, 194, 104, 64])
+      ),
+      0
+    )
+  ) {
+    return AggregatorInstruction.UpdateFeeTier;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([198, 212, 171, 109, 144, 215, 174, 89])
+      ),
+      0
+    )
+  ) {
+    return AggregatorInstruction.WithdrawFees;
+  }
   throw new Error(
     'The provided instruction could not be identified as a aggregator instruction.'
   );
@@ -123,18 +419,78 @@ export function identifyAggregatorInstruction(
 export type ParsedAggregatorInstruction<
   TProgram extends string = '7XEqP1W4vwMtPfkkgs97RnQSeksJHT1jSFuJvg3zm2Hs',
 > =
+  | ({
+      instructionType: AggregatorInstruction.AcceptAdmin;
+    } & ParsedAcceptAdminInstruction<TProgram>)
+  | ({
+      instructionType: AggregatorInstruction.CloseFeeTier;
+    } & ParsedCloseFeeTierInstruction<TProgram>)
+  | ({
+      instructionType: AggregatorInstruction.CreateFeeTier;
+    } & ParsedCreateFeeTierInstruction<TProgram>)
+  | ({
+      instructionType: AggregatorInstruction.InitAdapterRegistry;
+    } & ParsedInitAdapterRegistryInstruction<TProgram>)
   | ({
       instructionType: AggregatorInstruction.InitConfig;
     } & ParsedInitConfigInstruction<TProgram>)
+  | ({
+      instructionType: AggregatorInstruction.MigrateConfig;
+    } & ParsedMigrateConfigInstruction<TProgram>)
   | ({
       instructionType: AggregatorInstruction.Pause;
     } & ParsedPauseInstruction<TProgram>)
+  | ({
+      instructionType: AggregatorInstruction.PauseDex;
+    } & ParsedPauseDexInstruction<TProgram>)
+  | ({
+      instructionType: AggregatorInstruction.ProposeAdmin;
+    } & ParsedProposeAdminInstruction<TProgram>)
+  | ({
+      instructionType: AggregatorInstruction.RegisterReferrer;
+    } & ParsedRegisterReferrerInstruction<TProgram>)
+  | ({
+      instructionType: AggregatorInstruction.RemoveReferrer;
+    } & ParsedRemoveReferrerInstruction<TProgram>)
   | ({
       instructionType: AggregatorInstruction.Route;
     } & ParsedRouteInstruction<TProgram>)
+  | ({
+      instructionType: AggregatorInstruction.RouteExactOut;
+    } & ParsedRouteExactOutInstruction<TProgram>)
+  | ({
+      instructionType: AggregatorInstruction.RouteSplit;
+    } & ParsedRouteSplitInstruction<TProgram>)
+  | ({
+      instructionType: AggregatorInstruction.SetAdapter;
+    } & ParsedSetAdapterInstruction<TProgram>)
   | ({
       instructionType: AggregatorInstruction.SetConfig;
     } & ParsedSetConfigInstruction<TProgram>)
+  | ({
+      instructionType: AggregatorInstruction.SetFeeRecipient;
+    } & ParsedSetFeeRecipientInstruction<TProgram>)
+  | ({
+      instructionType: AggregatorInstruction.SetFeeSide;
+    } & ParsedSetFeeSideInstruction<TProgram>)
+  | ({
+      instructionType: AggregatorInstruction.SetMaxReferralBps;
+    } & ParsedSetMaxReferralBpsInstruction<TProgram>)
+  | ({
+      instructionType: AggregatorInstruction.SetOracleConfig;
+    } & ParsedSetOracleConfigInstruction<TProgram>)
+  | ({
+      instructionType: AggregatorInstruction.SetSurplusShareBps;
+    } & ParsedSetSurplusShareBpsInstruction<TProgram>)
   | ({
       instructionType: AggregatorInstruction.Unpause;
-    } & ParsedUnpauseInstruction<TProgram>);
+    } & ParsedUnpauseInstruction<TProgram>)
+  | ({
+      instructionType: AggregatorInstruction.UnpauseDex;
+    } & ParsedUnpauseDexInstruction<TProgram>)
+  | ({
+      instructionType: AggregatorInstruction.UpdateFeeTier;
+    } & ParsedUpdateFeeTierInstruction<TProgram>)
+  | ({
+      instructionType: AggregatorInstruction.WithdrawFees;
+    } & ParsedWithdrawFeesInstruction<TProgram>);
@@ -21,6 +21,10 @@ export enum DexId {
   SolarCp,
   SolarClmm,
   Invariant,
+  RaydiumAmmV4,
+  RaydiumCpmm,
+  RaydiumClmm,
+  MeteoraDlmm,
 }
 
 export type DexIdArgs = DexId;
@@ -6,6 +6,24 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './adapterEntry';
+export * from './adapterUpdated';
+export * from './adminProposed';
+export * from './adminTransferred';
+export * from './configMigrated';
 export * from './dexId';
+export * from './dexPauseChanged';
+export * from './feeRecipientUpdated';
+export * from './feeSide';
+export * from './feeTierClosed';
+export * from './feeTierUpdated';
+export * from './feesWithdrawn';
+export * from './legExecuted';
+export * from './referrerRegistered';
+export * from './referrerRemoved';
+export * from './routeBranch';
+export * from './routeExactOutExecuted';
 export * from './routeExecuted';
+export * from './routeOptions';
 export * from './swapLeg';
+export * from './swapParams';
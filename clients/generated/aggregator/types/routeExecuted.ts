@@ -23,15 +23,36 @@ import {
   type FixedSizeDecoder,
   type FixedSizeEncoder,
 } from '@solana/kit';
+import {
+  getFeeSideDecoder,
+  getFeeSideEncoder,
+  type FeeSide,
+  type FeeSideArgs,
+} from '.';
 
 export type RouteExecuted = {
   user: Address;
   inMint: Address;
   outMint: Address;
+  /** Input spent, including the fee with `FeeSide::Input`. */
   totalSpent: bigint;
+  /** Output credited to the user before the protocol fee (net of Token-2022 transfer fees). */
   totalOut: bigint;
+  /** Output sent by the pools before the mint's transfer fee. */
+  grossOut: bigint;
+  /** Output the user keeps after transfer fees, the protocol fee and the surplus fee. */
+  netOut: bigint;
+  /** Token-2022 transfer fees withheld by the output mint. */
+  transferFeeWithheld: bigint;
+  /** Total fee taken (`protocol_fee + referral_fee`), in the mint given by `fee_side`. */
   feeCharged: bigint;
+  feeSide: FeeSide;
+  protocolFee: bigint;
+  referralFee: bigint;
+  /** Positive-slippage fee charged on top of `fee_charged`. */
+  surplusFee: bigint;
   legs: number;
+  branches: number;
   feeBps: number;
 };
 
@@ -39,10 +60,25 @@ export type RouteExecutedArgs = {
   user: Address;
   inMint: Address;
   outMint: Address;
+  /** Input spent, including the fee with `FeeSide::Input`. */
   totalSpent: number | bigint;
+  /** Output credited to the user before the protocol fee (net of Token-2022 transfer fees). */
   totalOut: number | bigint;
+  /** Output sent by the pools before the mint's transfer fee. */
+  grossOut: number | bigint;
+  /** Output the user keeps after transfer fees, the protocol fee and the surplus fee. */
+  netOut: number | bigint;
+  /** Token-2022 transfer fees withheld by the output mint. */
+  transferFeeWithheld: number | bigint;
+  /** Total fee taken (`protocol_fee + referral_fee`), in the mint given by `fee_side`. */
   feeCharged: number | bigint;
+  feeSide: FeeSideArgs;
+  protocolFee: number | bigint;
+  referralFee: number | bigint;
+  /** Positive-slippage fee charged on top of `fee_charged`. */
+  surplusFee: number | bigint;
   legs: number;
+  branches: number;
   feeBps: number;
 };
 
@@ -53,8 +89,16 @@ export function getRouteExecutedEncoder(): FixedSizeEncoder<RouteExecutedArgs> {
     ['outMint', getAddressEncoder()],
     ['totalSpent', getU64Encoder()],
     ['totalOut', getU64Encoder()],
+    ['grossOut', getU64Encoder()],
+    ['netOut', getU64Encoder()],
+    ['transferFeeWithheld', getU64Encoder()],
     ['feeCharged', getU64Encoder()],
+    ['feeSide', getFeeSideEncoder()],
+    ['protocolFee', getU64Encoder()],
+    ['referralFee', getU64Encoder()],
+    ['surplusFee', getU64Encoder()],
     ['legs', getU8Encoder()],
+    ['branches', getU8Encoder()],
     ['feeBps', getU16Encoder()],
   ]);
 }
@@ -66,8 +110,16 @@ export function getRouteExecutedDecoder(): FixedSizeDecoder<RouteExecuted> {
     ['outMint', getAddressDecoder()],
     ['totalSpent', getU64Decoder()],
     ['totalOut', getU64Decoder()],
+    ['grossOut', getU64Decoder()],
+    ['netOut', getU64Decoder()],
+    ['transferFeeWithheld', getU64Decoder()],
     ['feeCharged', getU64Decoder()],
+    ['feeSide', getFeeSideDecoder()],
+    ['protocolFee', getU64Decoder()],
+    ['referralFee', getU64Decoder()],
+    ['surplusFee', getU64Decoder()],
     ['legs', getU8Decoder()],
+    ['branches', getU8Decoder()],
     ['feeBps', getU16Decoder()],
   ]);
 }
@@ -7,17 +7,11 @@
  */
 
 import {
-  addDecoderSizePrefix,
-  addEncoderSizePrefix,
   combineCodec,
   getAddressDecoder,
   getAddressEncoder,
-  getBytesDecoder,
-  getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
-  getU32Decoder,
-  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -26,34 +20,52 @@ import {
   type Codec,
   type Decoder,
   type Encoder,
-  type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
   getDexIdDecoder,
   getDexIdEncoder,
+  getSwapParamsDecoder,
+  getSwapParamsEncoder,
   type DexId,
   type DexIdArgs,
+  type SwapParams,
+  type SwapParamsArgs,
 } from '.';
 
 /**
  * Describes a single CPI leg into a downstream AMM.
  *
  * The router will forward `account_count` accounts from `ctx.remaining_accounts`
- * to the selected adapter based on `dex_id`. The adapter will then invoke the
- * AMM program with `data` as the raw instruction payload.
+ * to the selected adapter based on `dex_id`. The adapter will then encode
+ * `params` into the AMM's swap instruction and invoke the AMM program with it.
+ *
+ * Token programs are selected per leg: each leg's account list carries the SPL
+ * Token or Token-2022 program its pool expects, and both are accepted as
+ * account owners by every adapter.
  */
 
 export type SwapLeg = {
   /** Target DEX adapter. */
   dexId: DexId;
-  /** Intended input amount for this leg (hint; actual spent is computed post-swap). */
+  /**
+   * Intended input amount for this leg (hint; actual spent is computed post-swap).
+   * On exact-in routes only the first leg of a branch keeps this amount – later
+   * legs are rewritten to the measured output of the previous leg.
+   */
   inAmount: bigint;
-  /** Intended minimum output for this leg (hint; final slippage check is applied on net output after fees). */
+  /**
+   * Minimum output for this leg, enforced against the real balance delta of the
+   * leg's output token account (the final slippage check is still applied on
+   * net output after fees).
+   */
   minOut: bigint;
   /** Number of `AccountInfo`s to consume from `remaining_accounts` for this leg. */
   accountCount: number;
-  /** Raw instruction bytes for the AMM call (Anchor discriminator + Borsh-encoded args for that AMM instruction). */
-  data: ReadonlyUint8Array;
+  /**
+   * Typed arguments of the AMM swap; must belong to `dex_id` and agree with
+   * `in_amount` / `min_out`.
+   */
+  params: SwapParams;
   /** Expected input SPL mint for this leg (continuity-checked by router). */
   inMint: Address;
   /** Expected output SPL mint for this leg (continuity-checked by router). */
@@ -63,14 +75,25 @@ export type SwapLeg = {
 export type SwapLegArgs = {
   /** Target DEX adapter. */
   dexId: DexIdArgs;
-  /** Intended input amount for this leg (hint; actual spent is computed post-swap). */
+  /**
+   * Intended input amount for this leg (hint; actual spent is computed post-swap).
+   * On exact-in routes only the first leg of a branch keeps this amount – later
+   * legs are rewritten to the measured output of the previous leg.
+   */
   inAmount: number | bigint;
-  /** Intended minimum output for this leg (hint; final slippage check is applied on net output after fees). */
+  /**
+   * Minimum output for this leg, enforced against the real balance delta of the
+   * leg's output token account (the final slippage check is still applied on
+   * net output after fees).
+   */
   minOut: number | bigint;
   /** Number of `AccountInfo`s to consume from `remaining_accounts` for this leg. */
   accountCount: number;
-  /** Raw instruction bytes for the AMM call (Anchor discriminator + Borsh-encoded args for that AMM instruction). */
-  data: ReadonlyUint8Array;
+  /**
+   * Typed arguments of the AMM swap; must belong to `dex_id` and agree with
+   * `in_amount` / `min_out`.
+   */
+  params: SwapParamsArgs;
   /** Expected input SPL mint for this leg (continuity-checked by router). */
   inMint: Address;
   /** Expected output SPL mint for this leg (continuity-checked by router). */
@@ -83,7 +106,7 @@ export function getSwapLegEncoder(): Encoder<SwapLegArgs> {
     ['inAmount', getU64Encoder()],
     ['minOut', getU64Encoder()],
     ['accountCount', getU8Encoder()],
-    ['data', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
+    ['params', getSwapParamsEncoder()],
     ['inMint', getAddressEncoder()],
     ['outMint', getAddressEncoder()],
   ]);
@@ -95,7 +118,7 @@ export function getSwapLegDecoder(): Decoder<SwapLeg> {
     ['inAmount', getU64Decoder()],
     ['minOut', getU64Decoder()],
     ['accountCount', getU8Decoder()],
-    ['data', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
+    ['params', getSwapParamsDecoder()],
     ['inMint', getAddressDecoder()],
     ['outMint', getAddressDecoder()],
   ]);
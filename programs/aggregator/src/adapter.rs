@@ -1,25 +1,540 @@
 pub mod invariant;
 pub mod lifinity;
+pub mod meteora_dlmm;
 pub mod orca;
+pub mod raydium_amm;
+pub mod raydium_clmm;
+pub mod raydium_cpmm;
 pub mod solar_clmm;
 pub mod solar_cp;
 
-use crate::{DexId, SwapLeg};
+use crate::{
+    error::AggregatorError,
+    state::{AdapterEntry, AdapterRegistry, Config},
+    DexId, SwapLeg, SwapParams,
+};
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program, system_program,
+};
+use anchor_spl::token::ID as SPL_TOKEN_ID;
+use anchor_spl::token_2022::ID as TOKEN_2022_ID;
+
+/// Size of the base SPL token account layout (Token-2022 extensions follow it).
+const TOKEN_ACCOUNT_LEN: usize = 165;
+/// Byte range of the `mint` field in an SPL token account.
+const TOKEN_ACCOUNT_MINT: std::ops::Range<usize> = 0..32;
+/// Byte range of the `amount` field in an SPL token account.
+const TOKEN_ACCOUNT_AMOUNT: std::ops::Range<usize> = 64..72;
+
+/// Anchor discriminator of a `swap` instruction, shared by several AMMs.
+pub const SWAP_DISCRIMINATOR: [u8; 8] = [248, 198, 158, 145, 225, 117, 135, 200];
+
+/// Everything the router needs to know about one DEX's swap instruction.
+///
+/// Adapters are zero-sized types; the shared [`invoke`] path does the length
+/// checks, validation and CPI for all of them.  Adding a DEX means adding a
+/// [`DexId`] variant, its [`SwapParams`], implementing this trait and one arm in
+/// `with_adapter!`.
+pub trait DexAdapter {
+    /// Program ID the adapter was written against; seeds the registry.
+    const PROGRAM_ID: Pubkey;
+    /// Whether `init_adapter_registry` registers the adapter enabled.
+    const ENABLED_BY_DEFAULT: bool = true;
+    /// Account owners accepted by default besides the program and token programs.
+    const ALLOWED_OWNERS: &'static [Pubkey] = &[];
+    /// Leading bytes of the swap instructions legs may call (Anchor
+    /// discriminators, or the one-byte tag of native programs); anything else
+    /// (position management, admin calls, ...) is rejected.
+    const DISCRIMINATORS: &'static [&'static [u8]];
+
+    /// Encodes `params` as the AMM's swap instruction data.
+    fn encode(params: &SwapParams) -> Result<Vec<u8>>;
+
+    /// Positions `(input, output)` of the user's token accounts within the
+    /// leg's account list, which may depend on the instruction and direction
+    /// encoded in `data`.
+    fn token_accounts(data: &[u8]) -> Result<(usize, usize)>;
+
+    /// Rejects instruction data the adapter is not meant to forward.
+    fn check_instruction(data: &[u8]) -> Result<()> {
+        require!(
+            Self::DISCRIMINATORS
+                .iter()
+                .any(|discriminator| data.starts_with(discriminator)),
+            AggregatorError::DisallowedInstruction
+        );
+        Ok(())
+    }
+
+    /// Validates the accounts about to be forwarded with instruction `data`; by
+    /// default every account must be owned by a program the registry entry allows.
+    fn validate_accounts(
+        _data: &[u8],
+        accounts: &[AccountInfo<'_>],
+        entry: &AdapterEntry,
+    ) -> Result<()> {
+        for ai in accounts {
+            require!(
+                entry.allows_owner(ai.owner),
+                AggregatorError::InvalidProgramId
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Evaluates `$body` with `$adapter` bound to the [`DexAdapter`] of `$dex_id`.
+macro_rules! with_adapter {
+    ($dex_id:expr, |$adapter:ident| $body:expr) => {
+        match $dex_id {
+            DexId::LifinityV2 => {
+                type $adapter = lifinity::Lifinity;
+                $body
+            }
+            DexId::OrcaWhirlpool => {
+                type $adapter = orca::OrcaWhirlpool;
+                $body
+            }
+            DexId::SolarCp => {
+                type $adapter = solar_cp::SolarCp;
+                $body
+            }
+            DexId::SolarClmm => {
+                type $adapter = solar_clmm::SolarClmm;
+                $body
+            }
+            DexId::Invariant => {
+                type $adapter = invariant::Invariant;
+                $body
+            }
+            DexId::RaydiumAmmV4 => {
+                type $adapter = raydium_amm::RaydiumAmmV4;
+                $body
+            }
+            DexId::RaydiumCpmm => {
+                type $adapter = raydium_cpmm::RaydiumCpmm;
+                $body
+            }
+            DexId::RaydiumClmm => {
+                type $adapter = raydium_clmm::RaydiumClmm;
+                $body
+            }
+            DexId::MeteoraDlmm => {
+                type $adapter = meteora_dlmm::MeteoraDlmm;
+                $body
+            }
+        }
+    };
+}
+
+/// What a dispatched leg did, measured on its own token accounts.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LegFill {
+    /// The leg's input token account; `Pubkey::default()` for a leg without accounts.
+    pub input: Pubkey,
+    /// The leg's output token account; `Pubkey::default()` for a leg without accounts.
+    pub output: Pubkey,
+    /// Tokens that actually left `input`.
+    pub spent: u64,
+    /// Tokens that actually arrived in `output`.
+    pub received: u64,
+    /// Length of the slice of remaining accounts consumed by the adapter.
+    pub consumed: usize,
+}
 
 /// Dispatches a `SwapLeg` to the correct AMM adapter.
-/// Returns a tuple `(spent_in, received_out, accounts_consumed)`.
 ///
-/// * `spent_in` – tokens actually spent from user source.
-/// * `received_out` – tokens received to forward into next leg (or final out).
-/// * `accounts_consumed` – length of the slice of remaining accounts consumed by the adapter.
+/// Both amounts are balance deltas snapshotted around the CPI, never hints. A
+/// leg without accounts performs no CPI and therefore reports no token
+/// accounts and nothing moved.  The router links the reported accounts to
+/// `user_source`, `user_destination` and the neighbouring legs.
+///
+/// The target program and owner whitelist come from the [`AdapterRegistry`];
+/// DEXes paused in `cfg`, unregistered or disabled ones are rejected before
+/// anything else happens.
 #[inline(always)]
-pub fn dispatch<'info>(leg: &SwapLeg, rem: &[AccountInfo<'info>]) -> Result<(u64, u64, usize)> {
-    match leg.dex_id {
-        DexId::LifinityV2 => lifinity::invoke(leg, rem),
-        DexId::OrcaWhirlpool => orca::invoke(leg, rem),
-        DexId::SolarCp => solar_cp::invoke(leg, rem),
-        DexId::SolarClmm => solar_clmm::invoke(leg, rem),
-        DexId::Invariant => invariant::invoke(leg, rem),
+pub fn dispatch<'info>(
+    leg: &SwapLeg,
+    rem: &[AccountInfo<'info>],
+    registry: &AdapterRegistry,
+    cfg: &Config,
+) -> Result<LegFill> {
+    if cfg.is_dex_paused(leg.dex_id) {
+        msg!("DEX {:?} is paused", leg.dex_id);
+        return err!(AggregatorError::DexPaused);
+    }
+
+    let entry = registry
+        .get(leg.dex_id)
+        .ok_or(AggregatorError::UnknownDex)?;
+    if !entry.enabled {
+        msg!("DEX {:?} is disabled", leg.dex_id);
+        return err!(AggregatorError::AdapterDisabled);
     }
+    check_params(leg)?;
+    let data = encode(leg)?;
+
+    let needed = leg.account_count as usize;
+    require!(
+        rem.len() >= needed,
+        AggregatorError::RemainingAccountsMismatch
+    );
+
+    // Identify the leg's token accounts and snapshot them before the CPI.
+    let io = if needed == 0 {
+        None
+    } else {
+        let (in_idx, out_idx) = token_accounts(leg, &data)?;
+        let (input, output) = (&rem[in_idx], &rem[out_idx]);
+        let (in_mint, pre_in) = token_account_state(input)?;
+        let (out_mint, pre_out) = token_account_state(output)?;
+        require_keys_eq!(in_mint, leg.in_mint, AggregatorError::MintMismatch);
+        require_keys_eq!(out_mint, leg.out_mint, AggregatorError::MintMismatch);
+        Some((input, output, pre_in, pre_out))
+    };
+
+    let consumed = invoke_leg(leg, data, rem, entry)?;
+
+    let Some((input, output, pre_in, pre_out)) = io else {
+        return Ok(LegFill {
+            input: Pubkey::default(),
+            output: Pubkey::default(),
+            spent: 0,
+            received: 0,
+            consumed,
+        });
+    };
+    let spent = pre_in
+        .checked_sub(token_balance(input)?)
+        .ok_or(AggregatorError::NumericalOverflow)?;
+    let received = token_balance(output)?
+        .checked_sub(pre_out)
+        .ok_or(AggregatorError::NumericalOverflow)?;
+    Ok(LegFill {
+        input: input.key(),
+        output: output.key(),
+        spent,
+        received,
+        consumed,
+    })
+}
+
+/// Runs the shared [`invoke`] path with the adapter selected by `leg.dex_id`.
+pub fn invoke_leg<'info>(
+    leg: &SwapLeg,
+    data: Vec<u8>,
+    rem: &[AccountInfo<'info>],
+    entry: &AdapterEntry,
+) -> Result<usize> {
+    with_adapter!(leg.dex_id, |A| invoke::<A>(leg, data, rem, entry))
+}
+
+/// Forwards `data`, the leg's params as encoded by `A`, to `entry.program_id`
+/// with the leg's slice of `rem`, after the adapter's instruction and account
+/// validation.
+///
+/// Returns the number of accounts consumed.  The swapped amounts are not
+/// returned by the AMMs; [`dispatch`] measures them from the token accounts.
+pub fn invoke<'info, A: DexAdapter>(
+    leg: &SwapLeg,
+    data: Vec<u8>,
+    rem: &[AccountInfo<'info>],
+    entry: &AdapterEntry,
+) -> Result<usize> {
+    let needed = leg.account_count as usize;
+    require!(
+        rem.len() >= needed,
+        AggregatorError::RemainingAccountsMismatch
+    );
+
+    if needed == 0 {
+        return Ok(0);
+    }
+
+    let rem_slice = &rem[..needed];
+    let ix = prepare::<A>(data, rem_slice, entry)?;
+    program::invoke(&ix, rem_slice)?;
+
+    Ok(needed)
+}
+
+/// Builds the CPI [`invoke`] makes: checks the encoded swap `data` against
+/// `A`'s whitelist and validates `accounts` (the leg's slice of remaining
+/// accounts) before turning them into account metas.
+pub fn prepare<A: DexAdapter>(
+    data: Vec<u8>,
+    accounts: &[AccountInfo<'_>],
+    entry: &AdapterEntry,
+) -> Result<Instruction> {
+    A::check_instruction(&data)?;
+    A::validate_accounts(&data, accounts, entry)?;
+
+    let metas: Vec<AccountMeta> = accounts
+        .iter()
+        .map(|ai| AccountMeta {
+            pubkey: *ai.key,
+            is_signer: ai.is_signer,
+            is_writable: ai.is_writable,
+        })
+        .collect();
+
+    Ok(Instruction {
+        program_id: entry.program_id,
+        accounts: metas,
+        data,
+    })
+}
+
+/// DEXes the program ships an adapter for, in `DexId` order.
+pub const SUPPORTED_DEXES: &[DexId] = &[
+    DexId::LifinityV2,
+    DexId::OrcaWhirlpool,
+    DexId::SolarCp,
+    DexId::SolarClmm,
+    DexId::Invariant,
+    DexId::RaydiumAmmV4,
+    DexId::RaydiumCpmm,
+    DexId::RaydiumClmm,
+    DexId::MeteoraDlmm,
+];
+
+/// Registry entry seeded by `init_adapter_registry`, using the program IDs the
+/// adapters were written against. Admins override them per cluster with
+/// `set_adapter`.
+pub fn default_entry(dex_id: DexId) -> AdapterEntry {
+    with_adapter!(dex_id, |A| AdapterEntry {
+        dex_id,
+        program_id: A::PROGRAM_ID,
+        enabled: A::ENABLED_BY_DEFAULT,
+        allowed_owners: A::ALLOWED_OWNERS.to_vec(),
+    })
+}
+
+/// The leg's swap instruction data, as encoded by its adapter.
+pub fn encode(leg: &SwapLeg) -> Result<Vec<u8>> {
+    with_adapter!(leg.dex_id, |A| A::encode(&leg.params))
+}
+
+/// Anchor instruction data: `discriminator` followed by the Borsh-encoded `args`.
+fn anchor_data(discriminator: [u8; 8], args: impl AnchorSerialize) -> Result<Vec<u8>> {
+    let mut data = discriminator.to_vec();
+    args.serialize(&mut data)?;
+    Ok(data)
+}
+
+/// Checks the leg's params against the leg: they must belong to `dex_id`; an
+/// exact-input swap must spend exactly `in_amount`, and an exact-output swap
+/// must target at least `min_out` without allowing more than `in_amount` in.
+pub fn check_params(leg: &SwapLeg) -> Result<()> {
+    let params = &leg.params;
+    require!(
+        params.dex_id() == leg.dex_id,
+        AggregatorError::SwapParamsMismatch
+    );
+    let consistent = if params.is_exact_in() {
+        params.amount() == leg.in_amount
+    } else {
+        params.amount() >= leg.min_out
+            && params
+                .other_amount_threshold()
+                .map_or(true, |max_in| max_in <= leg.in_amount)
+    };
+    if !consistent {
+        msg!(
+            "Swap params {:?} disagree with in_amount {} / min_out {}",
+            params,
+            leg.in_amount,
+            leg.min_out
+        );
+        return err!(AggregatorError::SwapParamsMismatch);
+    }
+    Ok(())
+}
+
+impl SwapParams {
+    /// DEX whose adapter encodes these params.
+    pub fn dex_id(&self) -> DexId {
+        match self {
+            SwapParams::LifinityV2 { .. } => DexId::LifinityV2,
+            SwapParams::OrcaWhirlpool { .. } | SwapParams::OrcaWhirlpoolTwoHop { .. } => {
+                DexId::OrcaWhirlpool
+            }
+            SwapParams::SolarCp { .. } => DexId::SolarCp,
+            SwapParams::SolarClmm { .. } => DexId::SolarClmm,
+            SwapParams::Invariant { .. } => DexId::Invariant,
+            SwapParams::RaydiumAmmV4 { .. } => DexId::RaydiumAmmV4,
+            SwapParams::RaydiumCpmm { .. } => DexId::RaydiumCpmm,
+            SwapParams::RaydiumClmm { .. } => DexId::RaydiumClmm,
+            SwapParams::MeteoraDlmm { .. } => DexId::MeteoraDlmm,
+        }
+    }
+
+    /// Whether the swap is specified by its input amount.
+    pub fn is_exact_in(&self) -> bool {
+        match *self {
+            SwapParams::LifinityV2 { .. } => true,
+            SwapParams::OrcaWhirlpool {
+                amount_specified_is_input,
+                ..
+            }
+            | SwapParams::OrcaWhirlpoolTwoHop {
+                amount_specified_is_input,
+                ..
+            } => amount_specified_is_input,
+            SwapParams::SolarCp { base_input, .. }
+            | SwapParams::RaydiumAmmV4 { base_input, .. }
+            | SwapParams::RaydiumCpmm { base_input, .. }
+            | SwapParams::MeteoraDlmm { base_input, .. } => base_input,
+            SwapParams::SolarClmm { is_base_input, .. }
+            | SwapParams::RaydiumClmm { is_base_input, .. } => is_base_input,
+            SwapParams::Invariant { by_amount_in, .. } => by_amount_in,
+        }
+    }
+
+    /// The specified amount: the input of an exact-input swap, else the output.
+    pub fn amount(&self) -> u64 {
+        match *self {
+            SwapParams::LifinityV2 { amount_in, .. } => amount_in,
+            SwapParams::OrcaWhirlpool { amount, .. }
+            | SwapParams::OrcaWhirlpoolTwoHop { amount, .. }
+            | SwapParams::SolarCp { amount, .. }
+            | SwapParams::SolarClmm { amount, .. }
+            | SwapParams::Invariant { amount, .. }
+            | SwapParams::RaydiumAmmV4 { amount, .. }
+            | SwapParams::RaydiumCpmm { amount, .. }
+            | SwapParams::RaydiumClmm { amount, .. }
+            | SwapParams::MeteoraDlmm { amount, .. } => amount,
+        }
+    }
+
+    /// Minimum output (exact-input) or maximum input (exact-output) the AMM
+    /// enforces, if it takes one.
+    pub fn other_amount_threshold(&self) -> Option<u64> {
+        match *self {
+            SwapParams::LifinityV2 {
+                minimum_amount_out, ..
+            } => Some(minimum_amount_out),
+            SwapParams::OrcaWhirlpool {
+                other_amount_threshold,
+                ..
+            }
+            | SwapParams::OrcaWhirlpoolTwoHop {
+                other_amount_threshold,
+                ..
+            }
+            | SwapParams::SolarCp {
+                other_amount_threshold,
+                ..
+            }
+            | SwapParams::SolarClmm {
+                other_amount_threshold,
+                ..
+            }
+            | SwapParams::RaydiumAmmV4 {
+                other_amount_threshold,
+                ..
+            }
+            | SwapParams::RaydiumCpmm {
+                other_amount_threshold,
+                ..
+            }
+            | SwapParams::RaydiumClmm {
+                other_amount_threshold,
+                ..
+            }
+            | SwapParams::MeteoraDlmm {
+                other_amount_threshold,
+                ..
+            } => Some(other_amount_threshold),
+            SwapParams::Invariant { .. } => None,
+        }
+    }
+
+    /// Replaces the input amount of an exact-input swap.
+    pub fn set_amount_in(&mut self, value: u64) -> Result<()> {
+        require!(self.is_exact_in(), AggregatorError::SwapParamsMismatch);
+        match self {
+            SwapParams::LifinityV2 { amount_in, .. } => *amount_in = value,
+            SwapParams::OrcaWhirlpool { amount, .. }
+            | SwapParams::OrcaWhirlpoolTwoHop { amount, .. }
+            | SwapParams::SolarCp { amount, .. }
+            | SwapParams::SolarClmm { amount, .. }
+            | SwapParams::Invariant { amount, .. }
+            | SwapParams::RaydiumAmmV4 { amount, .. }
+            | SwapParams::RaydiumCpmm { amount, .. }
+            | SwapParams::RaydiumClmm { amount, .. }
+            | SwapParams::MeteoraDlmm { amount, .. } => *amount = value,
+        }
+        Ok(())
+    }
+}
+
+/// Positions `(input, output)` of the user's token accounts within the leg's
+/// slice of remaining accounts, as laid out by the leg's encoded instruction `data`.
+pub fn token_accounts(leg: &SwapLeg, data: &[u8]) -> Result<(usize, usize)> {
+    let (input, output) = with_adapter!(leg.dex_id, |A| A::token_accounts(data))?;
+    require!(
+        input < leg.account_count as usize && output < leg.account_count as usize,
+        AggregatorError::RemainingAccountsMismatch
+    );
+    Ok((input, output))
+}
+
+/// Overwrites the input amount of the leg's exact-input swap (and the
+/// `in_amount` hint).
+pub fn set_in_amount(leg: &mut SwapLeg, amount: u64) -> Result<()> {
+    leg.params.set_amount_in(amount)?;
+    leg.in_amount = amount;
+    Ok(())
+}
+
+/// Whether `program_id` is one of the supported token programs (SPL Token or Token-2022).
+pub fn is_token_program(program_id: &Pubkey) -> bool {
+    *program_id == SPL_TOKEN_ID || *program_id == TOKEN_2022_ID
+}
+
+/// Owner whitelist for swaps that also forward programs and system-owned
+/// accounts (wallets, data-less PDAs): an executable account must be a token
+/// program or one of `programs`, any other must be owned by a program `entry`
+/// allows or by the system program.
+pub fn check_owners(
+    accounts: &[AccountInfo<'_>],
+    entry: &AdapterEntry,
+    programs: &[Pubkey],
+) -> Result<()> {
+    for ai in accounts {
+        let allowed = if ai.executable {
+            is_token_program(ai.key) || programs.contains(ai.key)
+        } else {
+            entry.allows_owner(ai.owner) || *ai.owner == system_program::ID
+        };
+        require!(allowed, AggregatorError::InvalidProgramId);
+    }
+    Ok(())
+}
+
+/// Reads the live balance of a token account straight from its data.
+pub fn token_balance(ai: &AccountInfo<'_>) -> Result<u64> {
+    Ok(token_account_state(ai)?.1)
+}
+
+/// Reads `(mint, amount)` of a token account straight from its data.
+pub fn token_account_state(ai: &AccountInfo<'_>) -> Result<(Pubkey, u64)> {
+    require!(
+        is_token_program(ai.owner),
+        AggregatorError::InvalidTokenAccount
+    );
+    let data = ai.try_borrow_data()?;
+    require!(
+        data.len() >= TOKEN_ACCOUNT_LEN,
+        AggregatorError::InvalidTokenAccount
+    );
+    let mint = Pubkey::try_from(&data[TOKEN_ACCOUNT_MINT])
+        .map_err(|_| AggregatorError::InvalidTokenAccount)?;
+    let amount: [u8; 8] = data[TOKEN_ACCOUNT_AMOUNT]
+        .try_into()
+        .map_err(|_| AggregatorError::InvalidTokenAccount)?;
+    Ok((mint, u64::from_le_bytes(amount)))
 }
@@ -1,53 +1,41 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::{instruction::Instruction, program};
-use anchor_spl::token::ID as SPL_TOKEN_ID;
 
-use crate::{error::AggregatorError, SwapLeg};
+use super::{anchor_data, DexAdapter, SWAP_DISCRIMINATOR};
+use crate::{error::AggregatorError, SwapParams};
 
 pub const INVARIANT_PROGRAM_ID: Pubkey = pubkey!("S7Qs4dWfxDsSoCyHDwNoudSTRhkXSC5KSMKoGAncSHM");
 
-pub fn invoke<'info>(leg: &SwapLeg, rem: &[AccountInfo<'info>]) -> Result<(u64, u64, usize)> {
-    let needed = leg.account_count as usize;
-    require!(
-        rem.len() >= needed,
-        AggregatorError::RemainingAccountsMismatch
-    );
-
-    if needed == 0 {
-        return Ok((leg.in_amount, leg.min_out, 0));
-    }
-
-    let rem_slice = &rem[..needed];
-
-    // In unit tests we skip CPI and owner checks entirely
-    #[cfg(test)]
-    {
-        return Ok((leg.in_amount, leg.min_out, needed));
+/// Byte offset of the `x_to_y` direction flag.
+const X_TO_Y_OFFSET: usize = 8;
+
+/// Invariant `swap`.
+pub struct Invariant;
+
+impl DexAdapter for Invariant {
+    const DISCRIMINATORS: &'static [&'static [u8]] = &[&SWAP_DISCRIMINATOR];
+    const PROGRAM_ID: Pubkey = INVARIANT_PROGRAM_ID;
+
+    /// `swap(x_to_y: bool, amount: u64, by_amount_in: bool, sqrt_price_limit: u128)`.
+    fn encode(params: &SwapParams) -> Result<Vec<u8>> {
+        match *params {
+            SwapParams::Invariant {
+                x_to_y,
+                amount,
+                by_amount_in,
+                sqrt_price_limit,
+            } => anchor_data(
+                SWAP_DISCRIMINATOR,
+                (x_to_y, amount, by_amount_in, sqrt_price_limit),
+            ),
+            _ => err!(AggregatorError::SwapParamsMismatch),
+        }
     }
 
-    for ai in rem_slice {
-        let owner = *ai.owner;
-        require!(
-            owner == INVARIANT_PROGRAM_ID || owner == SPL_TOKEN_ID,
-            AggregatorError::InvalidProgramId
-        );
+    /// `account_x` sits at 5 and `account_y` at 6; `x_to_y` picks which is the input.
+    fn token_accounts(data: &[u8]) -> Result<(usize, usize)> {
+        let x_to_y = *data
+            .get(X_TO_Y_OFFSET)
+            .ok_or(AggregatorError::InvalidInstructionData)?;
+        Ok(if x_to_y != 0 { (5, 6) } else { (6, 5) })
     }
-
-    let metas: Vec<_> = rem_slice
-        .iter()
-        .map(|ai| anchor_lang::solana_program::instruction::AccountMeta {
-            pubkey: *ai.key,
-            is_signer: ai.is_signer,
-            is_writable: ai.is_writable,
-        })
-        .collect();
-
-    let ix = Instruction {
-        program_id: INVARIANT_PROGRAM_ID,
-        accounts: metas,
-        data: leg.data.clone(),
-    };
-    program::invoke(&ix, rem_slice)?;
-
-    Ok((leg.in_amount, leg.min_out, needed))
 }
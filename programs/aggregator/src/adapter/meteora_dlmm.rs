@@ -0,0 +1,125 @@
+//! Meteora DLMM adapter
+//! -------------------
+//! Forwards `swap` / `swap_exact_out`: lb_pair, bin_array_bitmap_extension,
+//! reserve_x, reserve_y, user_token_in, user_token_out, token_x_mint,
+//! token_y_mint, oracle, host_fee_in, user, token_x_program, token_y_program,
+//! event_authority and the DLMM program itself, followed by the bin arrays the
+//! swap crosses.
+//!
+//! The number of bin arrays depends on how far the swap moves the active bin,
+//! so the leg's `account_count` covers the fixed accounts plus every bin array
+//! and each of them must be owned by the DLMM program.  The optional bitmap
+//! extension and host fee account are passed as the DLMM program ID when unused,
+//! following Anchor's convention for `None` accounts.
+
+use anchor_lang::prelude::*;
+
+use super::{anchor_data, check_owners, is_token_program, DexAdapter, SWAP_DISCRIMINATOR};
+use crate::{error::AggregatorError, state::AdapterEntry, SwapParams};
+
+pub const METEORA_DLMM_PROGRAM_ID: Pubkey = pubkey!("LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo");
+
+/// Anchor discriminator of `swap_exact_out`.
+pub const SWAP_EXACT_OUT_DISCRIMINATOR: [u8; 8] = [250, 73, 101, 33, 38, 207, 75, 184];
+
+/// Fixed accounts of the swap instructions, before the bin arrays.
+const FIXED_ACCOUNTS: usize = 15;
+const LB_PAIR: usize = 0;
+const BITMAP_EXTENSION: usize = 1;
+const ORACLE: usize = 8;
+const HOST_FEE_IN: usize = 9;
+const DLMM_PROGRAM: usize = 14;
+/// reserve_x, reserve_y, token_x_mint and token_y_mint.
+const TOKEN_OWNED: [usize; 4] = [2, 3, 6, 7];
+/// token_x_program and token_y_program.
+const TOKEN_PROGRAMS: [usize; 2] = [11, 12];
+
+/// Meteora DLMM `swap` / `swap_exact_out`.
+pub struct MeteoraDlmm;
+
+impl DexAdapter for MeteoraDlmm {
+    const PROGRAM_ID: Pubkey = METEORA_DLMM_PROGRAM_ID;
+    const DISCRIMINATORS: &'static [&'static [u8]] =
+        &[&SWAP_DISCRIMINATOR, &SWAP_EXACT_OUT_DISCRIMINATOR];
+
+    /// `swap(amount_in: u64, min_amount_out: u64)` or
+    /// `swap_exact_out(max_in_amount: u64, out_amount: u64)`.
+    fn encode(params: &SwapParams) -> Result<Vec<u8>> {
+        match *params {
+            SwapParams::MeteoraDlmm {
+                amount,
+                other_amount_threshold,
+                base_input: true,
+            } => anchor_data(SWAP_DISCRIMINATOR, (amount, other_amount_threshold)),
+            SwapParams::MeteoraDlmm {
+                amount,
+                other_amount_threshold,
+                base_input: false,
+            } => anchor_data(
+                SWAP_EXACT_OUT_DISCRIMINATOR,
+                (other_amount_threshold, amount),
+            ),
+            _ => err!(AggregatorError::SwapParamsMismatch),
+        }
+    }
+
+    /// `user_token_in` and `user_token_out` sit at 4/5.
+    fn token_accounts(_data: &[u8]) -> Result<(usize, usize)> {
+        Ok((4, 5))
+    }
+
+    fn validate_accounts(
+        _data: &[u8],
+        accounts: &[AccountInfo<'_>],
+        entry: &AdapterEntry,
+    ) -> Result<()> {
+        // The active bin's array must follow the fixed accounts.
+        require!(
+            accounts.len() > FIXED_ACCOUNTS,
+            AggregatorError::RemainingAccountsMismatch
+        );
+
+        require_keys_eq!(
+            *accounts[DLMM_PROGRAM].key,
+            entry.program_id,
+            AggregatorError::InvalidProgramId
+        );
+        for i in TOKEN_PROGRAMS {
+            require!(
+                is_token_program(accounts[i].key),
+                AggregatorError::InvalidProgramId
+            );
+        }
+        // The pair, its oracle and every bin array belong to the DLMM program.
+        let dlmm_owned = [LB_PAIR, ORACLE]
+            .into_iter()
+            .chain(FIXED_ACCOUNTS..accounts.len());
+        for i in dlmm_owned {
+            require_keys_eq!(
+                *accounts[i].owner,
+                entry.program_id,
+                AggregatorError::InvalidProgramId
+            );
+        }
+        let bitmap_extension = &accounts[BITMAP_EXTENSION];
+        require!(
+            *bitmap_extension.key == entry.program_id
+                || *bitmap_extension.owner == entry.program_id,
+            AggregatorError::InvalidProgramId
+        );
+        let host_fee_in = &accounts[HOST_FEE_IN];
+        require!(
+            *host_fee_in.key == entry.program_id || is_token_program(host_fee_in.owner),
+            AggregatorError::InvalidTokenAccount
+        );
+        for i in TOKEN_OWNED {
+            require!(
+                is_token_program(accounts[i].owner),
+                AggregatorError::InvalidTokenAccount
+            );
+        }
+
+        // The user and the event authority PDA are system-owned.
+        check_owners(accounts, entry, &[entry.program_id])
+    }
+}
@@ -1,129 +1,292 @@
 //! Orca Whirlpool adapter
 //! ---------------------
-//! This module builds and invokes the Whirlpool `swap` instruction.  It is a *thin*
-//! wrapper whose responsibilities are:
-//!
-//! * Parse the [`SwapLeg`] metadata supplied by the router .
-//! * Perform basic safety checks (account slice length & owner whitelist).
-//! * Re-package the remaining accounts into [`AccountMeta`]s and forward the
-//!   raw instruction data (`leg.data`) to the on-chain Whirlpool program via
-//!   CPI.
-//! * Return `(spent, received, accounts_consumed)` so the router can advance
-//!   the `remaining_accounts` cursor.  **At present** we rely on the hints
-//!   encoded inside the leg, so `spent = in_amount` and `received = min_out`.
-//!   If/when Whirlpool exposes these values on-chain we can fetch the real
-//!   post-swap numbers here.
+//! Forwards Whirlpool `swap`, `swap_v2` and the two-hop swaps through the shared
+//! [`crate::adapter::invoke`] path.  Whirlpool does not return the swapped
+//! amounts to the caller, so the real `(spent, received)` are measured by
+//! [`crate::adapter::dispatch`] from the leg's token-account balances.
 //!
 //! The adapter is deliberately *stateless*: all authority / vault accounts are
 //! provided by the caller; the adapter never signs.
 //!
 //! ## Security barriers
 //!
-//! 1.  Owner whitelist ─ every account passed to the CPI must be owned by one
-//!     of: the Whirlpool program, the SPL-Token program, or the System program.
-//! 2.  Length check ─ prevents out-of-slice reads if the caller under-specifies
-//!     `leg.account_count`.
-//! 3.  **Test fast-path** ─ a zero-account leg returns immediately so the unit
-//!     tests don't need to construct real Whirlpool accounts.
+//! 1.  Owner whitelist ─ every account passed to the CPI must be owned by the
+//!     Whirlpool program, a token program, the System program (user wallets and
+//!     uninitialised oracle PDAs) or an owner the registry entry allows.
+//!     Program accounts must be SPL Token, Token-2022 or Memo.
+//! 2.  Positional checks ─ the whirlpool and its current tick array must be
+//!     owned by the Whirlpool program and the vaults by a token program, so a
+//!     route cannot swap against look-alike accounts.  The following tick arrays
+//!     are only read if the swap crosses into them, so they may also be
+//!     uninitialised (system-owned and empty), as Whirlpool itself allows.
 
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::{instruction::Instruction, program};
-use anchor_spl::token::ID as SPL_TOKEN_ID;
+use anchor_lang::solana_program::system_program;
 
-use crate::{error::AggregatorError, SwapLeg};
+use super::{anchor_data, check_owners, is_token_program, DexAdapter, SWAP_DISCRIMINATOR};
+use crate::{error::AggregatorError, state::AdapterEntry, SwapParams};
 
 /// Orca Whirlpool program-ID (mainnet-beta & localnet).
 /// Source: https://github.com/orca-so/whirlpools
 pub const ORCA_WHIRLPOOL_PROGRAM_ID: Pubkey =
     pubkey!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc");
 
-/// Invoke Orca Whirlpool `swap` instruction with detailed logging for traceability and debugging.
-pub fn invoke<'info>(leg: &SwapLeg, rem: &[AccountInfo<'info>]) -> Result<(u64, u64, usize)> {
-    let needed = leg.account_count as usize;
-    msg!(
-        "Orca adapter: preparing to invoke swap. Required accounts: {}",
-        needed
-    );
-
-    require!(
-        rem.len() >= needed,
-        AggregatorError::RemainingAccountsMismatch
-    );
-
-    if needed == 0 {
-        msg!("Orca adapter: No accounts required for this leg, returning early.");
-        return Ok((leg.in_amount, leg.min_out, 0));
-    }
+/// SPL Memo program, passed to `swap_v2` for Token-2022 memo transfers.
+pub const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+/// Anchor discriminator of Whirlpool `swap_v2` (Token-2022 aware layout).
+pub const SWAP_V2_DISCRIMINATOR: [u8; 8] = [43, 4, 237, 11, 26, 201, 30, 98];
+/// Anchor discriminator of Whirlpool `two_hop_swap`.
+pub const TWO_HOP_SWAP_DISCRIMINATOR: [u8; 8] = [195, 96, 237, 108, 68, 162, 219, 230];
+/// Anchor discriminator of Whirlpool `two_hop_swap_v2`.
+pub const TWO_HOP_SWAP_V2_DISCRIMINATOR: [u8; 8] = [186, 143, 209, 29, 254, 2, 194, 117];
 
-    let rem_slice = &rem[..needed];
+/// Byte offset of the `a_to_b` direction flag of `swap` / `swap_v2`.
+const A_TO_B_OFFSET: usize = 41;
+/// Byte offsets of `a_to_b_one` / `a_to_b_two` of the two-hop swaps.
+const A_TO_B_ONE_OFFSET: usize = 25;
+const A_TO_B_TWO_OFFSET: usize = 26;
+
+/// Account positions of a Whirlpool swap instruction.
+struct Layout {
+    discriminator: [u8; 8],
+    /// Accounts the instruction requires; the v2 instructions may be followed
+    /// by supplemental tick arrays.
+    min_accounts: usize,
+    /// Token program accounts.
+    token_programs: &'static [usize],
+    memo_program: Option<usize>,
+    /// Whirlpool(s) followed by their current tick arrays.
+    whirlpool_owned: &'static [usize],
+    /// Tick arrays past the current one, which may be left uninitialised.
+    next_tick_arrays: &'static [usize],
+    vaults: &'static [usize],
+    /// Resolves the user's `(input, output)` token accounts from the args.
+    token_accounts: fn(&[u8]) -> Result<(usize, usize)>,
+}
+
+fn flag(data: &[u8], offset: usize) -> Result<bool> {
+    Ok(*data
+        .get(offset)
+        .ok_or(AggregatorError::InvalidInstructionData)?
+        != 0)
+}
+
+/// `swap`: token_program, token_authority, whirlpool, token_owner_account_a,
+/// token_vault_a, token_owner_account_b, token_vault_b, tick_array_0..2, oracle.
+const SWAP_LAYOUT: Layout = Layout {
+    discriminator: SWAP_DISCRIMINATOR,
+    min_accounts: 11,
+    token_programs: &[0],
+    memo_program: None,
+    whirlpool_owned: &[2, 7],
+    next_tick_arrays: &[8, 9],
+    vaults: &[4, 6],
+    token_accounts: |data| {
+        Ok(if flag(data, A_TO_B_OFFSET)? {
+            (3, 5)
+        } else {
+            (5, 3)
+        })
+    },
+};
+
+/// `swap_v2`: token_program_a/b, memo_program, token_authority, whirlpool,
+/// token_mint_a/b, token_owner_account_a, token_vault_a, token_owner_account_b,
+/// token_vault_b, tick_array_0..2, oracle.
+const SWAP_V2_LAYOUT: Layout = Layout {
+    discriminator: SWAP_V2_DISCRIMINATOR,
+    min_accounts: 15,
+    token_programs: &[0, 1],
+    memo_program: Some(2),
+    whirlpool_owned: &[4, 11],
+    next_tick_arrays: &[12, 13],
+    vaults: &[8, 10],
+    token_accounts: |data| {
+        Ok(if flag(data, A_TO_B_OFFSET)? {
+            (7, 9)
+        } else {
+            (9, 7)
+        })
+    },
+};
+
+/// `two_hop_swap`: token_program, token_authority, whirlpool_one/two, then
+/// owner/vault pairs for one_a, one_b, two_a, two_b, tick_array_one_0..2,
+/// tick_array_two_0..2, oracle_one/two.
+const TWO_HOP_SWAP_LAYOUT: Layout = Layout {
+    discriminator: TWO_HOP_SWAP_DISCRIMINATOR,
+    min_accounts: 20,
+    token_programs: &[0],
+    memo_program: None,
+    whirlpool_owned: &[2, 3, 12, 15],
+    next_tick_arrays: &[13, 14, 16, 17],
+    vaults: &[5, 7, 9, 11],
+    token_accounts: |data| {
+        let input = if flag(data, A_TO_B_ONE_OFFSET)? { 4 } else { 6 };
+        let output = if flag(data, A_TO_B_TWO_OFFSET)? {
+            10
+        } else {
+            8
+        };
+        Ok((input, output))
+    },
+};
+
+/// `two_hop_swap_v2`: whirlpool_one/two, token_mint_input/intermediate/output,
+/// token_program_input/intermediate/output, token_owner_account_input, four
+/// vaults, token_owner_account_output, token_authority, tick_array_one_0..2,
+/// tick_array_two_0..2, oracle_one/two, memo_program.
+const TWO_HOP_SWAP_V2_LAYOUT: Layout = Layout {
+    discriminator: TWO_HOP_SWAP_V2_DISCRIMINATOR,
+    min_accounts: 24,
+    token_programs: &[5, 6, 7],
+    memo_program: Some(23),
+    whirlpool_owned: &[0, 1, 15, 18],
+    next_tick_arrays: &[16, 17, 19, 20],
+    vaults: &[9, 10, 11, 12],
+    token_accounts: |_| Ok((8, 13)),
+};
+
+const LAYOUTS: &[Layout] = &[
+    SWAP_LAYOUT,
+    SWAP_V2_LAYOUT,
+    TWO_HOP_SWAP_LAYOUT,
+    TWO_HOP_SWAP_V2_LAYOUT,
+];
+
+fn layout(data: &[u8]) -> Result<&'static Layout> {
+    LAYOUTS
+        .iter()
+        .find(|layout| data.starts_with(&layout.discriminator))
+        .ok_or(AggregatorError::DisallowedInstruction.into())
+}
 
-    // In unit tests we skip CPI and owner checks entirely
-    #[cfg(test)]
-    {
-        msg!("Orca adapter: Test mode, skipping CPI and owner checks.");
-        return Ok((leg.in_amount, leg.min_out, needed));
+pub struct OrcaWhirlpool;
+
+impl DexAdapter for OrcaWhirlpool {
+    const PROGRAM_ID: Pubkey = ORCA_WHIRLPOOL_PROGRAM_ID;
+
+    const DISCRIMINATORS: &'static [&'static [u8]] = &[
+        &SWAP_DISCRIMINATOR,
+        &SWAP_V2_DISCRIMINATOR,
+        &TWO_HOP_SWAP_DISCRIMINATOR,
+        &TWO_HOP_SWAP_V2_DISCRIMINATOR,
+    ];
+
+    /// `swap(amount: u64, other_amount_threshold: u64, sqrt_price_limit: u128,
+    /// amount_specified_is_input: bool, a_to_b: bool)` and `two_hop_swap(amount,
+    /// other_amount_threshold, amount_specified_is_input, a_to_b_one, a_to_b_two,
+    /// sqrt_price_limit_one, sqrt_price_limit_two)`; the v2 variants append
+    /// `remaining_accounts_info: Option<RemainingAccountsInfo>`, always `None`.
+    fn encode(params: &SwapParams) -> Result<Vec<u8>> {
+        const NO_REMAINING_ACCOUNTS_INFO: Option<()> = None;
+        match *params {
+            SwapParams::OrcaWhirlpool {
+                amount,
+                other_amount_threshold,
+                sqrt_price_limit,
+                amount_specified_is_input,
+                a_to_b,
+                v2,
+            } => {
+                let args = (
+                    amount,
+                    other_amount_threshold,
+                    sqrt_price_limit,
+                    amount_specified_is_input,
+                    a_to_b,
+                );
+                if v2 {
+                    anchor_data(SWAP_V2_DISCRIMINATOR, (args, NO_REMAINING_ACCOUNTS_INFO))
+                } else {
+                    anchor_data(SWAP_DISCRIMINATOR, args)
+                }
+            }
+            SwapParams::OrcaWhirlpoolTwoHop {
+                amount,
+                other_amount_threshold,
+                amount_specified_is_input,
+                a_to_b_one,
+                a_to_b_two,
+                sqrt_price_limit_one,
+                sqrt_price_limit_two,
+                v2,
+            } => {
+                let args = (
+                    amount,
+                    other_amount_threshold,
+                    amount_specified_is_input,
+                    a_to_b_one,
+                    a_to_b_two,
+                    sqrt_price_limit_one,
+                    sqrt_price_limit_two,
+                );
+                if v2 {
+                    anchor_data(
+                        TWO_HOP_SWAP_V2_DISCRIMINATOR,
+                        (args, NO_REMAINING_ACCOUNTS_INFO),
+                    )
+                } else {
+                    anchor_data(TWO_HOP_SWAP_DISCRIMINATOR, args)
+                }
+            }
+            _ => err!(AggregatorError::SwapParamsMismatch),
+        }
     }
 
-    // Log account keys and signer/writable status for traceability
-    msg!("Orca adapter: Account list for CPI:");
-    for (i, ai) in rem_slice.iter().enumerate() {
-        msg!(
-            "  [{}] {} | signer: {} | writable: {} | owner: {}",
-            i,
-            ai.key,
-            ai.is_signer,
-            ai.is_writable,
-            ai.owner
-        );
+    /// `swap` places `token_owner_account_a/b` at 3/5, `swap_v2` at 7/9; the
+    /// two-hop swaps read the first hop's input and the second hop's output.
+    fn token_accounts(data: &[u8]) -> Result<(usize, usize)> {
+        (layout(data)?.token_accounts)(data)
     }
 
-    // Owner whitelist check for every account (production)
-    // for ai in rem_slice {
-    //     let owner = *ai.owner;
-    //     require!(
-    //         owner == ORCA_WHIRLPOOL_PROGRAM_ID || owner == SPL_TOKEN_ID,
-    //         AggregatorError::InvalidProgramId
-    //     );
-    // }
+    fn validate_accounts(
+        data: &[u8],
+        accounts: &[AccountInfo<'_>],
+        entry: &AdapterEntry,
+    ) -> Result<()> {
+        let layout = layout(data)?;
+        require!(
+            accounts.len() >= layout.min_accounts,
+            AggregatorError::RemainingAccountsMismatch
+        );
 
-    let metas: Vec<anchor_lang::solana_program::instruction::AccountMeta> = rem_slice
-        .iter()
-        .map(|ai| anchor_lang::solana_program::instruction::AccountMeta {
-            pubkey: *ai.key,
-            is_signer: ai.is_signer,
-            is_writable: ai.is_writable,
-        })
-        .collect();
-
-    msg!(
-        "Orca adapter: Building CPI instruction. in_amount: {}, min_out: {}, data_len: {}",
-        leg.in_amount,
-        leg.min_out,
-        leg.data.len()
-    );
-
-    let ix = Instruction {
-        program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
-        accounts: metas,
-        data: leg.data.clone(),
-    };
-
-    msg!("Orca adapter: Invoking Orca Whirlpool program via CPI...");
-    let cpi_result = program::invoke(&ix, rem_slice);
-
-    match cpi_result {
-        Ok(_) => {
-            msg!(
-                "Orca adapter: CPI successful. Spent: {}, Received: {}, Accounts consumed: {}",
-                leg.in_amount,
-                leg.min_out,
-                needed
+        for &i in layout.token_programs {
+            require!(
+                is_token_program(accounts[i].key),
+                AggregatorError::InvalidProgramId
             );
-            Ok((leg.in_amount, leg.min_out, needed))
         }
-        Err(e) => {
-            msg!("Orca adapter: CPI failed with error: {:?}", e);
-            Err(e.into())
+        if let Some(i) = layout.memo_program {
+            require_keys_eq!(
+                *accounts[i].key,
+                MEMO_PROGRAM_ID,
+                AggregatorError::InvalidProgramId
+            );
+        }
+        for &i in layout.whirlpool_owned {
+            require_keys_eq!(
+                *accounts[i].owner,
+                entry.program_id,
+                AggregatorError::InvalidProgramId
+            );
+        }
+        for &i in layout.next_tick_arrays {
+            let ai = &accounts[i];
+            require!(
+                *ai.owner == entry.program_id
+                    || (*ai.owner == system_program::ID && ai.data_is_empty()),
+                AggregatorError::InvalidProgramId
+            );
+        }
+        for &i in layout.vaults {
+            require!(
+                is_token_program(accounts[i].owner),
+                AggregatorError::InvalidTokenAccount
+            );
         }
+
+        check_owners(accounts, entry, &[MEMO_PROGRAM_ID])
     }
 }
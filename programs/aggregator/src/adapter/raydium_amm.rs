@@ -0,0 +1,151 @@
+//! Raydium AMM v4 adapter
+//! ---------------------
+//! AMM v4 is a native (non-Anchor) program: instructions start with a one-byte
+//! tag followed by little-endian arguments.  The legacy `swap_base_in` /
+//! `swap_base_out` take the 18-account OpenBook layout (including
+//! `amm_target_orders`); the `_v2` instructions drop the order-book accounts.
+//!
+//! Positional checks pin the pool to the AMM program, the vaults to SPL Token
+//! and the market program to an owner the registry entry allows (OpenBook by
+//! default), on top of the usual owner whitelist.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::ID as SPL_TOKEN_ID;
+
+use super::{check_owners, DexAdapter};
+use crate::{error::AggregatorError, state::AdapterEntry, SwapParams};
+
+pub const RAYDIUM_AMM_V4_PROGRAM_ID: Pubkey =
+    pubkey!("675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8");
+
+/// OpenBook (Serum v3) program owning the market accounts of legacy swaps.
+pub const OPENBOOK_PROGRAM_ID: Pubkey = pubkey!("srmqPvymJeFKQ4zGQed1GFppgkRHL9kaELCbyksJtPX");
+
+/// Instruction tags of the swap instructions.
+pub const SWAP_BASE_IN_TAG: u8 = 9;
+pub const SWAP_BASE_OUT_TAG: u8 = 11;
+pub const SWAP_BASE_IN_V2_TAG: u8 = 16;
+pub const SWAP_BASE_OUT_V2_TAG: u8 = 17;
+
+/// Account positions of an AMM v4 swap instruction.
+struct Layout {
+    accounts: usize,
+    /// Index of the OpenBook program, absent from the v2 swaps.
+    market_program: Option<usize>,
+    vaults: [usize; 2],
+    user_source: usize,
+    user_destination: usize,
+}
+
+/// token_program, amm, amm_authority, amm_open_orders, amm_target_orders,
+/// pool_coin_vault, pool_pc_vault, serum_program, serum_market, serum_bids,
+/// serum_asks, serum_event_queue, serum_coin_vault, serum_pc_vault,
+/// serum_vault_signer, user_source, user_destination, user_owner.
+const LEGACY_LAYOUT: Layout = Layout {
+    accounts: 18,
+    market_program: Some(7),
+    vaults: [5, 6],
+    user_source: 15,
+    user_destination: 16,
+};
+
+/// token_program, amm, amm_authority, amm_coin_vault, amm_pc_vault,
+/// user_source, user_destination, user_owner.
+const V2_LAYOUT: Layout = Layout {
+    accounts: 8,
+    market_program: None,
+    vaults: [3, 4],
+    user_source: 5,
+    user_destination: 6,
+};
+
+fn layout(data: &[u8]) -> Result<&'static Layout> {
+    match data.first() {
+        Some(&SWAP_BASE_IN_TAG) | Some(&SWAP_BASE_OUT_TAG) => Ok(&LEGACY_LAYOUT),
+        Some(&SWAP_BASE_IN_V2_TAG) | Some(&SWAP_BASE_OUT_V2_TAG) => Ok(&V2_LAYOUT),
+        _ => err!(AggregatorError::DisallowedInstruction),
+    }
+}
+
+/// Raydium AMM v4 `swap_base_in` / `swap_base_out` and their `_v2` variants.
+pub struct RaydiumAmmV4;
+
+impl DexAdapter for RaydiumAmmV4 {
+    const PROGRAM_ID: Pubkey = RAYDIUM_AMM_V4_PROGRAM_ID;
+    const ALLOWED_OWNERS: &'static [Pubkey] = &[OPENBOOK_PROGRAM_ID];
+    const DISCRIMINATORS: &'static [&'static [u8]] = &[
+        &[SWAP_BASE_IN_TAG],
+        &[SWAP_BASE_OUT_TAG],
+        &[SWAP_BASE_IN_V2_TAG],
+        &[SWAP_BASE_OUT_V2_TAG],
+    ];
+
+    /// `swap_base_in(amount_in: u64, minimum_amount_out: u64)` or
+    /// `swap_base_out(max_amount_in: u64, amount_out: u64)`.
+    fn encode(params: &SwapParams) -> Result<Vec<u8>> {
+        let SwapParams::RaydiumAmmV4 {
+            amount,
+            other_amount_threshold,
+            base_input,
+            v2,
+        } = *params
+        else {
+            return err!(AggregatorError::SwapParamsMismatch);
+        };
+        let (tag, args) = match (base_input, v2) {
+            (true, false) => (SWAP_BASE_IN_TAG, (amount, other_amount_threshold)),
+            (true, true) => (SWAP_BASE_IN_V2_TAG, (amount, other_amount_threshold)),
+            (false, false) => (SWAP_BASE_OUT_TAG, (other_amount_threshold, amount)),
+            (false, true) => (SWAP_BASE_OUT_V2_TAG, (other_amount_threshold, amount)),
+        };
+        let mut data = vec![tag];
+        args.serialize(&mut data)?;
+        Ok(data)
+    }
+
+    /// `user_source` / `user_destination` sit at 15/16, or 5/6 for the v2 swaps.
+    fn token_accounts(data: &[u8]) -> Result<(usize, usize)> {
+        let layout = layout(data)?;
+        Ok((layout.user_source, layout.user_destination))
+    }
+
+    fn validate_accounts(
+        data: &[u8],
+        accounts: &[AccountInfo<'_>],
+        entry: &AdapterEntry,
+    ) -> Result<()> {
+        let layout = layout(data)?;
+        // AMM v4 takes no supplemental accounts.
+        require!(
+            accounts.len() == layout.accounts,
+            AggregatorError::RemainingAccountsMismatch
+        );
+
+        require_keys_eq!(
+            *accounts[0].key,
+            SPL_TOKEN_ID,
+            AggregatorError::InvalidProgramId
+        );
+        require_keys_eq!(
+            *accounts[1].owner,
+            entry.program_id,
+            AggregatorError::InvalidProgramId
+        );
+        for i in layout.vaults {
+            require_keys_eq!(
+                *accounts[i].owner,
+                SPL_TOKEN_ID,
+                AggregatorError::InvalidTokenAccount
+            );
+        }
+        if let Some(i) = layout.market_program {
+            require!(
+                entry.allowed_owners.contains(accounts[i].key),
+                AggregatorError::InvalidProgramId
+            );
+        }
+
+        // The AMM authority and the market's vault signer are data-less PDAs.
+        check_owners(accounts, entry, &entry.allowed_owners)
+    }
+}
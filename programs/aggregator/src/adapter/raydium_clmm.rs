@@ -0,0 +1,117 @@
+//! Raydium CLMM adapter
+//! -------------------
+//! Forwards `swap_v2` (`SwapSingleV2`): payer, amm_config, pool_state,
+//! input_token_account, output_token_account, input_vault, output_vault,
+//! observation_state, token_program, token_program_2022, memo_program,
+//! input_vault_mint and output_vault_mint, followed by the tick-array bitmap
+//! extension (when the swap crosses outside the pool's default bitmap) and the
+//! tick arrays the swap walks through.
+//!
+//! Those trailing accounts vary per route, so they are counted in the leg's
+//! `account_count` and must all be owned by the CLMM program.  Solar CLMM is a
+//! fork of this program and shares the `swap_v2` discriminator.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::ID as SPL_TOKEN_ID;
+use anchor_spl::token_2022::ID as TOKEN_2022_ID;
+
+use super::orca::MEMO_PROGRAM_ID;
+use super::solar_clmm::SWAP_V2_DISCRIMINATOR;
+use super::{anchor_data, check_owners, is_token_program, DexAdapter};
+use crate::{error::AggregatorError, state::AdapterEntry, SwapParams};
+
+pub const RAYDIUM_CLMM_PROGRAM_ID: Pubkey = pubkey!("CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK");
+
+/// Fixed accounts of `swap_v2`, before the bitmap extension and tick arrays.
+const FIXED_ACCOUNTS: usize = 13;
+/// amm_config, pool_state and observation_state.
+const CLMM_OWNED: [usize; 3] = [1, 2, 7];
+/// input_vault, output_vault, input_vault_mint and output_vault_mint.
+const TOKEN_OWNED: [usize; 4] = [5, 6, 11, 12];
+const TOKEN_PROGRAM: usize = 8;
+const TOKEN_PROGRAM_2022: usize = 9;
+const MEMO_PROGRAM: usize = 10;
+
+/// Raydium concentrated-liquidity `swap_v2`.
+pub struct RaydiumClmm;
+
+impl DexAdapter for RaydiumClmm {
+    const PROGRAM_ID: Pubkey = RAYDIUM_CLMM_PROGRAM_ID;
+    const DISCRIMINATORS: &'static [&'static [u8]] = &[&SWAP_V2_DISCRIMINATOR];
+
+    /// `swap_v2(amount: u64, other_amount_threshold: u64, sqrt_price_limit_x64: u128, is_base_input: bool)`.
+    fn encode(params: &SwapParams) -> Result<Vec<u8>> {
+        match *params {
+            SwapParams::RaydiumClmm {
+                amount,
+                other_amount_threshold,
+                sqrt_price_limit_x64,
+                is_base_input,
+            } => anchor_data(
+                SWAP_V2_DISCRIMINATOR,
+                (
+                    amount,
+                    other_amount_threshold,
+                    sqrt_price_limit_x64,
+                    is_base_input,
+                ),
+            ),
+            _ => err!(AggregatorError::SwapParamsMismatch),
+        }
+    }
+
+    /// `input_token_account` and `output_token_account` sit at 3/4.
+    fn token_accounts(_data: &[u8]) -> Result<(usize, usize)> {
+        Ok((3, 4))
+    }
+
+    fn validate_accounts(
+        _data: &[u8],
+        accounts: &[AccountInfo<'_>],
+        entry: &AdapterEntry,
+    ) -> Result<()> {
+        // At least the pool's current tick array must follow the fixed accounts.
+        require!(
+            accounts.len() > FIXED_ACCOUNTS,
+            AggregatorError::RemainingAccountsMismatch
+        );
+
+        require_keys_eq!(
+            *accounts[TOKEN_PROGRAM].key,
+            SPL_TOKEN_ID,
+            AggregatorError::InvalidProgramId
+        );
+        require_keys_eq!(
+            *accounts[TOKEN_PROGRAM_2022].key,
+            TOKEN_2022_ID,
+            AggregatorError::InvalidProgramId
+        );
+        require_keys_eq!(
+            *accounts[MEMO_PROGRAM].key,
+            MEMO_PROGRAM_ID,
+            AggregatorError::InvalidProgramId
+        );
+        // The pool, its config and observation, the bitmap extension and every
+        // tick array belong to the CLMM program.
+        let clmm_owned = CLMM_OWNED
+            .iter()
+            .copied()
+            .chain(FIXED_ACCOUNTS..accounts.len());
+        for i in clmm_owned {
+            require_keys_eq!(
+                *accounts[i].owner,
+                entry.program_id,
+                AggregatorError::InvalidProgramId
+            );
+        }
+        for i in TOKEN_OWNED {
+            require!(
+                is_token_program(accounts[i].owner),
+                AggregatorError::InvalidTokenAccount
+            );
+        }
+
+        // The payer is a system-owned wallet.
+        check_owners(accounts, entry, &[MEMO_PROGRAM_ID])
+    }
+}
@@ -0,0 +1,107 @@
+//! Raydium CPMM (CP-Swap) adapter
+//! -----------------------------
+//! Solar CP is a fork of CP-Swap, so both share instruction set and account
+//! layout: payer, authority, amm_config, pool_state, input_token_account,
+//! output_token_account, input_vault, output_vault, input/output token
+//! programs, input/output mints and observation_state.
+//!
+//! Positional checks pin the config, pool and observation accounts to the pool
+//! program and the vaults and mints to a token program, on top of the usual
+//! owner whitelist; [`validate_cp_swap_accounts`] runs them for both adapters.
+
+use anchor_lang::prelude::*;
+
+use super::solar_cp::{SWAP_BASE_INPUT_DISCRIMINATOR, SWAP_BASE_OUTPUT_DISCRIMINATOR};
+use super::{anchor_data, check_owners, is_token_program, DexAdapter};
+use crate::{error::AggregatorError, state::AdapterEntry, SwapParams};
+
+pub const RAYDIUM_CPMM_PROGRAM_ID: Pubkey = pubkey!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C");
+
+/// Accounts of `swap_base_input` / `swap_base_output`.
+const ACCOUNTS: usize = 13;
+/// amm_config, pool_state and observation_state.
+const CPMM_OWNED: [usize; 3] = [2, 3, 12];
+/// input_vault, output_vault, input_token_mint and output_token_mint.
+const TOKEN_OWNED: [usize; 4] = [6, 7, 10, 11];
+/// input_token_program and output_token_program.
+const TOKEN_PROGRAMS: [usize; 2] = [8, 9];
+
+/// Raydium CPMM `swap_base_input` / `swap_base_output`.
+pub struct RaydiumCpmm;
+
+impl DexAdapter for RaydiumCpmm {
+    const PROGRAM_ID: Pubkey = RAYDIUM_CPMM_PROGRAM_ID;
+    const DISCRIMINATORS: &'static [&'static [u8]] = &[
+        &SWAP_BASE_INPUT_DISCRIMINATOR,
+        &SWAP_BASE_OUTPUT_DISCRIMINATOR,
+    ];
+
+    /// `swap_base_input(amount_in: u64, minimum_amount_out: u64)` or
+    /// `swap_base_output(max_amount_in: u64, amount_out: u64)`.
+    fn encode(params: &SwapParams) -> Result<Vec<u8>> {
+        match *params {
+            SwapParams::RaydiumCpmm {
+                amount,
+                other_amount_threshold,
+                base_input: true,
+            } => anchor_data(
+                SWAP_BASE_INPUT_DISCRIMINATOR,
+                (amount, other_amount_threshold),
+            ),
+            SwapParams::RaydiumCpmm {
+                amount,
+                other_amount_threshold,
+                base_input: false,
+            } => anchor_data(
+                SWAP_BASE_OUTPUT_DISCRIMINATOR,
+                (other_amount_threshold, amount),
+            ),
+            _ => err!(AggregatorError::SwapParamsMismatch),
+        }
+    }
+
+    /// `input_token_account` and `output_token_account` sit at 4/5.
+    fn token_accounts(_data: &[u8]) -> Result<(usize, usize)> {
+        Ok((4, 5))
+    }
+
+    fn validate_accounts(
+        _data: &[u8],
+        accounts: &[AccountInfo<'_>],
+        entry: &AdapterEntry,
+    ) -> Result<()> {
+        validate_cp_swap_accounts(accounts, entry)
+    }
+}
+
+/// Account checks of a CP-Swap `swap_base_input` / `swap_base_output`, against
+/// the pool program of `entry` (Raydium CPMM or Solar CP).
+pub fn validate_cp_swap_accounts(accounts: &[AccountInfo<'_>], entry: &AdapterEntry) -> Result<()> {
+    require!(
+        accounts.len() == ACCOUNTS,
+        AggregatorError::RemainingAccountsMismatch
+    );
+
+    for i in TOKEN_PROGRAMS {
+        require!(
+            is_token_program(accounts[i].key),
+            AggregatorError::InvalidProgramId
+        );
+    }
+    for i in CPMM_OWNED {
+        require_keys_eq!(
+            *accounts[i].owner,
+            entry.program_id,
+            AggregatorError::InvalidProgramId
+        );
+    }
+    for i in TOKEN_OWNED {
+        require!(
+            is_token_program(accounts[i].owner),
+            AggregatorError::InvalidTokenAccount
+        );
+    }
+
+    // The payer and the vault authority PDA are system-owned.
+    check_owners(accounts, entry, &[])
+}
@@ -1,55 +1,52 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::{instruction::Instruction, program};
-use anchor_spl::token::ID as SPL_TOKEN_ID;
 
-use crate::{error::AggregatorError, SwapLeg};
+use super::{anchor_data, DexAdapter, SWAP_DISCRIMINATOR};
+use crate::{error::AggregatorError, SwapParams};
 
 // WARNING : This program has been closed on eclipse mainnet
 pub const SOLAR_CLMM_PROGRAM_ID: Pubkey = pubkey!("CLsiWisG9Ek7dFgD8ENYtxLBbMEVxB64M3wD4f2XuZSn");
 
-pub fn invoke<'info>(leg: &SwapLeg, rem: &[AccountInfo<'info>]) -> Result<(u64, u64, usize)> {
-    let needed = leg.account_count as usize;
-    require!(
-        rem.len() >= needed,
-        AggregatorError::RemainingAccountsMismatch
-    );
-
-    if needed == 0 {
-        return Ok((leg.in_amount, leg.min_out, 0));
-    }
-
-    let rem_slice = &rem[..needed];
-
-    // In unit tests we skip CPI and owner checks entirely
-    #[cfg(test)]
-    {
-        return Ok((leg.in_amount, leg.min_out, needed));
+/// Anchor discriminator of `swap_v2` (Token-2022 aware, same account prefix).
+pub const SWAP_V2_DISCRIMINATOR: [u8; 8] = [43, 4, 237, 11, 26, 201, 30, 98];
+
+/// Solar concentrated-liquidity `swap`.
+pub struct SolarClmm;
+
+impl DexAdapter for SolarClmm {
+    const PROGRAM_ID: Pubkey = SOLAR_CLMM_PROGRAM_ID;
+    // Closed on Eclipse mainnet – registered but disabled until re-enabled.
+    const ENABLED_BY_DEFAULT: bool = false;
+    const DISCRIMINATORS: &'static [&'static [u8]] = &[&SWAP_DISCRIMINATOR, &SWAP_V2_DISCRIMINATOR];
+
+    /// `swap(amount: u64, other_amount_threshold: u64, sqrt_price_limit_x64: u128, is_base_input: bool)`,
+    /// shared by `swap_v2`.
+    fn encode(params: &SwapParams) -> Result<Vec<u8>> {
+        match *params {
+            SwapParams::SolarClmm {
+                amount,
+                other_amount_threshold,
+                sqrt_price_limit_x64,
+                is_base_input,
+                v2,
+            } => anchor_data(
+                if v2 {
+                    SWAP_V2_DISCRIMINATOR
+                } else {
+                    SWAP_DISCRIMINATOR
+                },
+                (
+                    amount,
+                    other_amount_threshold,
+                    sqrt_price_limit_x64,
+                    is_base_input,
+                ),
+            ),
+            _ => err!(AggregatorError::SwapParamsMismatch),
+        }
     }
 
-    // Whitelist owner validation
-    for ai in rem_slice {
-        let owner = *ai.owner;
-        require!(
-            owner == SOLAR_CLMM_PROGRAM_ID || owner == SPL_TOKEN_ID,
-            AggregatorError::InvalidProgramId
-        );
+    /// `input_token_account` and `output_token_account` sit at 3/4.
+    fn token_accounts(_data: &[u8]) -> Result<(usize, usize)> {
+        Ok((3, 4))
     }
-
-    let metas: Vec<_> = rem_slice
-        .iter()
-        .map(|ai| anchor_lang::solana_program::instruction::AccountMeta {
-            pubkey: *ai.key,
-            is_signer: ai.is_signer,
-            is_writable: ai.is_writable,
-        })
-        .collect();
-
-    let ix = Instruction {
-        program_id: SOLAR_CLMM_PROGRAM_ID,
-        accounts: metas,
-        data: leg.data.clone(),
-    };
-    program::invoke(&ix, rem_slice)?;
-
-    Ok((leg.in_amount, leg.min_out, needed))
 }
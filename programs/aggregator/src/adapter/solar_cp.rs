@@ -1,53 +1,60 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::{instruction::Instruction, program};
-use anchor_spl::token::ID as SPL_TOKEN_ID;
 
-use crate::{error::AggregatorError, SwapLeg};
+use super::raydium_cpmm::validate_cp_swap_accounts;
+use super::{anchor_data, DexAdapter};
+use crate::{error::AggregatorError, state::AdapterEntry, SwapParams};
 
 pub const SOLAR_CP_PROGRAM_ID: Pubkey = pubkey!("CXwUb1EA4caBLm31LWXbfbg1NtPVKXbcXSLa3wCPQiaY");
 
-pub fn invoke<'info>(leg: &SwapLeg, rem: &[AccountInfo<'info>]) -> Result<(u64, u64, usize)> {
-    let needed = leg.account_count as usize;
-    require!(
-        rem.len() >= needed,
-        AggregatorError::RemainingAccountsMismatch
-    );
-
-    if needed == 0 {
-        return Ok((leg.in_amount, leg.min_out, 0));
+/// Anchor discriminators of `swap_base_input` and `swap_base_output`.
+pub const SWAP_BASE_INPUT_DISCRIMINATOR: [u8; 8] = [143, 190, 90, 218, 196, 30, 51, 222];
+pub const SWAP_BASE_OUTPUT_DISCRIMINATOR: [u8; 8] = [55, 217, 98, 86, 163, 74, 180, 173];
+
+/// Solar constant-product `swap_base_input` / `swap_base_output`.
+pub struct SolarCp;
+
+impl DexAdapter for SolarCp {
+    const PROGRAM_ID: Pubkey = SOLAR_CP_PROGRAM_ID;
+    const DISCRIMINATORS: &'static [&'static [u8]] = &[
+        &SWAP_BASE_INPUT_DISCRIMINATOR,
+        &SWAP_BASE_OUTPUT_DISCRIMINATOR,
+    ];
+
+    /// `swap_base_input(amount_in: u64, minimum_amount_out: u64)` or
+    /// `swap_base_output(max_amount_in: u64, amount_out: u64)`.
+    fn encode(params: &SwapParams) -> Result<Vec<u8>> {
+        match *params {
+            SwapParams::SolarCp {
+                amount,
+                other_amount_threshold,
+                base_input: true,
+            } => anchor_data(
+                SWAP_BASE_INPUT_DISCRIMINATOR,
+                (amount, other_amount_threshold),
+            ),
+            SwapParams::SolarCp {
+                amount,
+                other_amount_threshold,
+                base_input: false,
+            } => anchor_data(
+                SWAP_BASE_OUTPUT_DISCRIMINATOR,
+                (other_amount_threshold, amount),
+            ),
+            _ => err!(AggregatorError::SwapParamsMismatch),
+        }
     }
 
-    let rem_slice = &rem[..needed];
-
-    // In unit tests we skip CPI and owner checks entirely
-    #[cfg(test)]
-    {
-        return Ok((leg.in_amount, leg.min_out, needed));
+    /// `input_token_account` and `output_token_account` sit at 4/5.
+    fn token_accounts(_data: &[u8]) -> Result<(usize, usize)> {
+        Ok((4, 5))
     }
 
-    for ai in rem_slice {
-        let owner = *ai.owner;
-        require!(
-            owner == SOLAR_CP_PROGRAM_ID || owner == SPL_TOKEN_ID,
-            AggregatorError::InvalidProgramId
-        );
+    /// Same layout and checks as Raydium CPMM, against the Solar CP program.
+    fn validate_accounts(
+        _data: &[u8],
+        accounts: &[AccountInfo<'_>],
+        entry: &AdapterEntry,
+    ) -> Result<()> {
+        validate_cp_swap_accounts(accounts, entry)
     }
-
-    let metas: Vec<_> = rem_slice
-        .iter()
-        .map(|ai| anchor_lang::solana_program::instruction::AccountMeta {
-            pubkey: *ai.key,
-            is_signer: ai.is_signer,
-            is_writable: ai.is_writable,
-        })
-        .collect();
-
-    let ix = Instruction {
-        program_id: SOLAR_CP_PROGRAM_ID,
-        accounts: metas,
-        data: leg.data.clone(),
-    };
-    program::invoke(&ix, rem_slice)?;
-
-    Ok((leg.in_amount, leg.min_out, needed))
 }
@@ -22,7 +22,7 @@ pub enum AggregatorError {
     MintMismatch,
     #[msg("Route must contain at least one leg")]
     NoLegs,
-    #[msg("Fee vault owner does not match admin")]
+    #[msg("Fee vault is not owned by the fee authority PDA")]
     FeeVaultOwnerMismatch,
     #[msg("Invalid fee basis points (must be 0-10000)")]
     InvalidFeeBps,
@@ -30,4 +30,72 @@ pub enum AggregatorError {
     TooManyLegs,
     #[msg("Numerical overflow")]
     NumericalOverflow,
+    #[msg("Exact-out legs do not cover the requested output")]
+    ExactOutPlanMismatch,
+    #[msg("Too many branches in split route")]
+    TooManyBranches,
+    #[msg("Swap instruction data is malformed for the selected adapter")]
+    InvalidInstructionData,
+    #[msg("Account is not a valid token account")]
+    InvalidTokenAccount,
+    #[msg("A swap leg delivered less than its min_out")]
+    LegSlippageExceeded,
+    #[msg("Token account is not a wrapped SOL account")]
+    NotNativeMint,
+    #[msg("An optional account required by the selected route options is missing")]
+    MissingOptionalAccount,
+    #[msg("Adapter is disabled in the registry")]
+    AdapterDisabled,
+    #[msg("Adapter registry is full")]
+    RegistryFull,
+    #[msg("Too many allowed owners for adapter")]
+    TooManyAllowedOwners,
+    #[msg("No admin transfer is pending")]
+    NoPendingAdmin,
+    #[msg("Config account is not the expected PDA")]
+    InvalidConfigAccount,
+    #[msg("Token account is not a program-owned fee vault")]
+    InvalidFeeVault,
+    #[msg("Fee withdrawal destination is not owned by the fee recipient")]
+    FeeDestinationMismatch,
+    #[msg("Referral share exceeds the configured maximum")]
+    ReferralBpsTooHigh,
+    #[msg("Fee tier does not apply to this route's mints")]
+    FeeTierMismatch,
+    #[msg("Surplus share exceeds the maximum")]
+    InvalidSurplusShareBps,
+    #[msg("Route submitted after its deadline")]
+    Expired,
+    #[msg("Price feed account is invalid for this mint")]
+    InvalidPriceFeed,
+    #[msg("Price feed is stale")]
+    StalePrice,
+    #[msg("Route rate deviates too far from the oracle price")]
+    PriceDeviationExceeded,
+    #[msg("Instruction is not a whitelisted swap for this adapter")]
+    DisallowedInstruction,
+    #[msg("Swap params do not match the leg's DEX, in_amount or min_out")]
+    SwapParamsMismatch,
+    #[msg("Quoted output is below the output the route's minimum requires")]
+    QuoteBelowMinOut,
+    #[msg("Referral share must go to a registered referrer")]
+    UnregisteredReferrer,
+    #[msg("The user cannot be paid its own referral share")]
+    SelfReferral,
+    #[msg("Price feeds are required for every route")]
+    OracleRequired,
+    #[msg("Temporary WSOL account is not the expected PDA")]
+    InvalidWsolAccount,
+    #[msg("wrap_lamports exceeds user_max_in")]
+    WrapExceedsMaxIn,
+    #[msg("A leg does not spend from user_source or the previous leg's output account")]
+    LegInputMismatch,
+    #[msg("A branch does not deliver to user_destination")]
+    LegOutputMismatch,
+    #[msg("DEX is paused")]
+    DexPaused,
+    #[msg("Oracle-guarded route spent or received nothing")]
+    ZeroOracleAmount,
+    #[msg("Max price deviation must be at most 10000 bps")]
+    InvalidPriceDeviationBps,
 }
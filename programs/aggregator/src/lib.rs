@@ -1,15 +1,21 @@
 #![allow(deprecated)]
-use crate::state::Config;
+use crate::state::{
+    AdapterEntry, AdapterRegistry, Config, FeeTier, Referrer, MAX_ALLOWED_OWNERS,
+    MAX_SURPLUS_SHARE_BPS,
+};
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::System;
-use anchor_spl::associated_token::get_associated_token_address;
-use anchor_spl::token::{self, Token, TokenAccount};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 
 mod adapter;
 pub mod error;
+pub mod oracle;
+mod router;
 pub mod state;
+mod transfer_fee;
 
 use error::AggregatorError;
+use router::SwapMode;
 
 declare_id!("7XEqP1W4vwMtPfkkgs97RnQSeksJHT1jSFuJvg3zm2Hs");
 
@@ -20,202 +26,203 @@ pub mod aggregator {
     /// Route executes a user-supplied swap path through one or more DEX adapters.  
     ///
     /// * `legs` ‑ ordered list of [`SwapLeg`] descriptions. Each leg specifies which
-    ///   adapter (`dex_id`) to call, the expected input/output SPL mints and the raw
-    ///   CPI data blob that will be forwarded to the adapter.  
+    ///   adapter (`dex_id`) to call, the expected input/output SPL mints and typed
+    ///   [`SwapParams`] from which the program encodes the adapter's instruction.  
     /// * `user_max_in` ‑ hard cap on the number of input tokens the user is willing
-    ///   to spend across the whole route (**checked after execution** using the real
-    ///   balance delta).  
+    ///   to spend across the whole route, including a fee charged on the input
+    ///   side (**checked after execution** using the real balance delta).  
     /// * `user_min_out` ‑ minimum number of destination tokens the user expects to
     ///   receive in total (**true anti-slippage check** – evaluated post-swap using
-    ///   the actual output).
+    ///   the actual output).  For Token-2022 mints with a transfer fee this is the
+    ///   amount the user nets after both the transfer fee and the protocol fee.
+    /// * `options` ‑ optional behaviour, see [`RouteOptions`] (native SOL
+    ///   wrap/unwrap, referral fee share, surplus quote, deadline).
     ///
     /// Security-wise the instruction enforces:  
-    /// 1. protocol pause switch  
-    /// 2. mint continuity between legs  
-    /// 3. fee-vault mint/address correctness  
-    /// 4. accurate spend / receive accounting driven by live token balances  
-    /// 5. automatic fee transfer to the configured vault  
+    /// 1. protocol pause switch and the optional slot / timestamp deadline  
+    /// 2. mint continuity between legs, with each hop consuming exactly the
+    ///    measured output of the previous one  
+    /// 3. per-leg `min_out` on the measured output of every hop, and the
+    ///    optional oracle price-deviation guard on the whole route  
+    /// 4. fee-vault mint/address correctness (program-owned PDA per output mint)  
+    /// 5. accurate spend / receive accounting driven by live token balances  
+    /// 6. automatic fee transfer to the vault, created on first use at `payer`'s
+    ///    expense  
     ///
-    /// The protocol fee ( `cfg.fee_bps` ) is **not** provided by the client anymore;
-    /// it is read exclusively from the on-chain [`Config`] PDA.
+    /// The protocol fee is **not** provided by the client anymore; it is read from
+    /// the [`FeeTier`] PDA for the route's mints (or the any-input tier for the
+    /// output mint) when it exists, falling back to `cfg.fee_bps` in the on-chain
+    /// [`Config`] PDA.
     pub fn route(
         ctx: Context<RouteAccounts>,
         legs: Vec<SwapLeg>,
         user_max_in: u64,
         user_min_out: u64,
+        options: RouteOptions,
     ) -> Result<()> {
-        let mut rem_accs = ctx.remaining_accounts;
+        let settlement = router::execute(
+            ctx.accounts,
+            ctx.remaining_accounts,
+            &[legs.as_slice()],
+            SwapMode::ExactIn,
+            user_max_in,
+            user_min_out,
+            &options,
+        )?;
 
-        // ------------------------------------------------------------------
-        // Snapshot balances – we'll use the deltas later to compute the exact
-        // amount spent/received and to implement slippage + fee checks.
-        // ------------------------------------------------------------------
-        let pre_src_balance = ctx.accounts.user_source.amount;
-        let pre_dest_balance = ctx.accounts.user_destination.amount;
-
-        // Governance config
-        let cfg = &ctx.accounts.config;
-
-        // ------------------------------------------------------------------
-        // Global safety gates & basic route sanity checks
-        // ------------------------------------------------------------------
-        // 1) Protocol pause switch
-        require!(!cfg.paused, AggregatorError::Paused);
-
-        // 2) Ensure token accounts are controlled by the caller (fail-fast)
-        require_keys_eq!(
-            ctx.accounts.user_source.owner,
-            ctx.accounts.user_authority.key(),
-            AggregatorError::Unauthorized
-        );
-        require_keys_eq!(
-            ctx.accounts.user_destination.owner,
-            ctx.accounts.user_authority.key(),
-            AggregatorError::Unauthorized
-        );
-
-        // 3) Ensure first leg consumes the tokens provided in `user_source`
-        if let Some(first_leg) = legs.first() {
-            require_keys_eq!(
-                first_leg.in_mint,
-                ctx.accounts.user_source.mint,
-                AggregatorError::MintMismatch
-            );
-        }
-
-        // 4) Empty route not allowed – protects against accidental fee burn
-        require!(legs.len() > 0, AggregatorError::NoLegs);
-
-        // 5) Bound the number of legs
+        // Enforce the user-supplied minimum-out slippage guard using the **net** amount.
         require!(
-            legs.len() <= MAX_LEGS as usize,
-            AggregatorError::TooManyLegs
+            settlement.user_receive()? >= user_min_out,
+            AggregatorError::SlippageExceeded
         );
 
-        let mut prev_out_mint: Option<Pubkey> = None;
-
-        for (_i, leg) in legs.iter().enumerate() {
-            // Enforce mint continuity across legs (out_mint of previous == in_mint of next)
-            // Mint continuity check
-            if let Some(prev) = prev_out_mint {
-                require_keys_eq!(leg.in_mint, prev, AggregatorError::MintMismatch);
-            }
+        if options.unwrap_sol {
+            router::unwrap_sol(ctx.accounts, settlement.user_receive()?)?;
+        }
 
-            // Each adapter will consume some of the remaining accounts slice; we ignore any reported amounts for security.
-            let (_spent_hint, _received_hint, consumed) = adapter::dispatch(leg, rem_accs)?;
-            // Defense-in-depth: adapter must consume exactly what the leg declares
-            require!(
-                consumed == leg.account_count as usize,
-                AggregatorError::RemainingAccountsMismatch
-            );
-            require!(
-                consumed <= rem_accs.len(),
-                AggregatorError::RemainingAccountsMismatch
-            );
-            rem_accs = &rem_accs[consumed..];
+        // Emit an event for analytics and auditing
+        emit!(RouteExecuted {
+            user: ctx.accounts.user_authority.key(),
+            in_mint: settlement.in_mint,
+            out_mint: ctx.accounts.user_destination.mint,
+            total_spent: settlement.delta_spent,
+            total_out: settlement.delta_out,
+            gross_out: settlement.gross_out,
+            net_out: settlement.user_receive()?,
+            transfer_fee_withheld: settlement.transfer_fee_withheld,
+            fee_charged: settlement.fee_amount,
+            fee_side: settlement.fee_side,
+            protocol_fee: settlement.protocol_fee()?,
+            referral_fee: settlement.referral_fee,
+            surplus_fee: settlement.surplus_fee,
+            legs: settlement.legs,
+            branches: 1,
+            fee_bps: settlement.fee_bps,
+        });
 
-            // Update prev_out_mint for next iteration
-            prev_out_mint = Some(leg.out_mint);
-        }
+        // Final state: tokens already in user_destination (minus fee). No extra action.
+        Ok(())
+    }
 
-        // ------------------------------------------------------------------
-        // Post-execution accounting & user-side limits
-        // ------------------------------------------------------------------
-        // Reload destination to fetch post-swap balance
-        ctx.accounts.user_destination.reload()?;
-        let post_dest_balance = ctx.accounts.user_destination.amount;
-        // Reload source to compute how many tokens were actually spent
-        ctx.accounts.user_source.reload()?;
-        let post_src_balance = ctx.accounts.user_source.amount;
-        let delta_spent = pre_src_balance
-            .checked_sub(post_src_balance)
-            .ok_or(AggregatorError::NumericalOverflow)?;
-        require!(
-            delta_spent <= user_max_in,
-            AggregatorError::TooManyTokensSpent
-        );
-        let delta_out = post_dest_balance
-            .checked_sub(pre_dest_balance)
-            .ok_or(AggregatorError::NumericalOverflow)?;
-
-        // ------------------------------------------------------------------
-        // Fee calculation & transfer – based on *real* output to make fee-
-        // exploitation (e.g. via hints) impossible.
-        // fee_bps is the fee in basis points (1/100 of a percent)
-        // formula: fee_amount = (delta_out * fee_bps) / 10_000
-        // ------------------------------------------------------------------
-        let fee_amount: u64 = ((delta_out as u128 * cfg.fee_bps as u128) / 10_000u128)
-            .try_into()
-            .map_err(|_| AggregatorError::NumericalOverflow)?;
-
-        // Net amount that ends up in the user's destination account *after* fee deduction.
-        let user_receive = delta_out
-            .checked_sub(fee_amount)
-            .ok_or(AggregatorError::NumericalOverflow)?;
+    /// Split variant of [`route`]: the order is divided across parallel branches,
+    /// e.g. 60/40 across two pools of the same pair.
+    ///
+    /// Each [`RouteBranch`] is a linear chain of legs that starts from
+    /// `user_source` and converges on `user_destination`; the usual continuity
+    /// checks apply per branch and the legs of all branches count towards
+    /// [`MAX_LEGS`].  Remaining accounts are consumed branch by branch in order.
+    ///
+    /// `user_max_in`, the protocol fee and `user_min_out` are evaluated once, on
+    /// the aggregate balance deltas of the whole route.
+    pub fn route_split(
+        ctx: Context<RouteAccounts>,
+        branches: Vec<RouteBranch>,
+        user_max_in: u64,
+        user_min_out: u64,
+        options: RouteOptions,
+    ) -> Result<()> {
+        let branch_legs: Vec<&[SwapLeg]> = branches.iter().map(|b| b.legs.as_slice()).collect();
+        let settlement = router::execute(
+            ctx.accounts,
+            ctx.remaining_accounts,
+            &branch_legs,
+            SwapMode::ExactIn,
+            user_max_in,
+            user_min_out,
+            &options,
+        )?;
 
-        // Enforce the user-supplied minimum-out slippage guard using the **net** amount.
+        // Slippage guard on the aggregate **net** amount.
         require!(
-            user_receive >= user_min_out,
+            settlement.user_receive()? >= user_min_out,
             AggregatorError::SlippageExceeded
         );
 
-        // Ensure final out mint matches user_destination mint if any legs executed
-        if let Some(final_mint) = prev_out_mint {
-            require_keys_eq!(
-                final_mint,
-                ctx.accounts.user_destination.mint,
-                AggregatorError::MintMismatch
-            );
+        if options.unwrap_sol {
+            router::unwrap_sol(ctx.accounts, settlement.user_receive()?)?;
         }
 
-        // Ensure fee vault mint matches the final output mint to prevent griefing.
-        require_keys_eq!(
-            ctx.accounts.fee_vault.mint,
-            ctx.accounts.user_destination.mint,
-            AggregatorError::FeeVaultMintMismatch
-        );
+        emit!(RouteExecuted {
+            user: ctx.accounts.user_authority.key(),
+            in_mint: settlement.in_mint,
+            out_mint: ctx.accounts.user_destination.mint,
+            total_spent: settlement.delta_spent,
+            total_out: settlement.delta_out,
+            gross_out: settlement.gross_out,
+            net_out: settlement.user_receive()?,
+            transfer_fee_withheld: settlement.transfer_fee_withheld,
+            fee_charged: settlement.fee_amount,
+            fee_side: settlement.fee_side,
+            protocol_fee: settlement.protocol_fee()?,
+            referral_fee: settlement.referral_fee,
+            surplus_fee: settlement.surplus_fee,
+            legs: settlement.legs,
+            branches: branches.len() as u8,
+            fee_bps: settlement.fee_bps,
+        });
 
-        // Validate that the provided fee_vault is the admin's ATA for the final out mint.
-        let expected_fee_vault =
-            get_associated_token_address(&cfg.admin, &ctx.accounts.user_destination.mint);
-        require_keys_eq!(
-            ctx.accounts.fee_vault.key(),
-            expected_fee_vault,
-            AggregatorError::FeeVaultMintMismatch
-        );
+        Ok(())
+    }
 
-        // Extra safety: ensure the fee vault is owned by the configured admin.
-        require_keys_eq!(
-            ctx.accounts.fee_vault.owner,
-            cfg.admin,
-            AggregatorError::FeeVaultOwnerMismatch
+    /// Exact-output variant of [`route`]: the user asks for exactly `desired_out`
+    /// destination tokens (net of the protocol fee) and caps spend with `user_max_in`.
+    ///
+    /// The client plans the legs backwards from the destination, so each leg's
+    /// `min_out` is what the following leg needs as input and the last leg's
+    /// `min_out` covers `desired_out` grossed up by the route's fee rate.  The router
+    /// re-validates that plan before dispatching and, after execution, enforces
+    /// `delta_out >= desired_out + fee` on the real balance delta.
+    ///
+    /// Unused input never leaves `user_source` – spend is measured from the live
+    /// balance, so anything the legs did not consume stays with the user.  An
+    /// input-side fee is the exception: it is charged up front on the first
+    /// leg's `in_amount` cap.
+    pub fn route_exact_out(
+        ctx: Context<RouteAccounts>,
+        legs: Vec<SwapLeg>,
+        user_max_in: u64,
+        desired_out: u64,
+        options: RouteOptions,
+    ) -> Result<()> {
+        let settlement = router::execute(
+            ctx.accounts,
+            ctx.remaining_accounts,
+            &[legs.as_slice()],
+            SwapMode::ExactOut,
+            user_max_in,
+            desired_out,
+            &options,
+        )?;
+
+        let delivered_out = settlement.user_receive()?;
+        require!(
+            delivered_out >= desired_out,
+            AggregatorError::SlippageExceeded
         );
 
-        if fee_amount > 0 {
-            let cpi_ctx = token::Transfer {
-                from: ctx.accounts.user_destination.to_account_info(),
-                to: ctx.accounts.fee_vault.to_account_info(),
-                authority: ctx.accounts.user_authority.to_account_info(),
-            };
-            token::transfer(
-                CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_ctx),
-                fee_amount,
-            )?;
+        if options.unwrap_sol {
+            router::unwrap_sol(ctx.accounts, delivered_out)?;
         }
 
-        // Emit an event for analytics and auditing
-        emit!(RouteExecuted {
+        emit!(RouteExactOutExecuted {
             user: ctx.accounts.user_authority.key(),
-            in_mint: ctx.accounts.user_source.mint,
+            in_mint: settlement.in_mint,
             out_mint: ctx.accounts.user_destination.mint,
-            total_spent: delta_spent,
-            total_out: delta_out,
-            fee_charged: fee_amount,
-            legs: legs.len() as u8,
-            fee_bps: cfg.fee_bps,
+            max_in: user_max_in,
+            total_spent: settlement.delta_spent,
+            requested_out: desired_out,
+            delivered_out,
+            gross_out: settlement.gross_out,
+            transfer_fee_withheld: settlement.transfer_fee_withheld,
+            fee_charged: settlement.fee_amount,
+            fee_side: settlement.fee_side,
+            protocol_fee: settlement.protocol_fee()?,
+            referral_fee: settlement.referral_fee,
+            surplus_fee: settlement.surplus_fee,
+            legs: settlement.legs,
+            fee_bps: settlement.fee_bps,
         });
 
-        // Final state: tokens already in user_destination (minus fee). No extra action.
         Ok(())
     }
 
@@ -227,6 +234,16 @@ pub mod aggregator {
         cfg.fee_bps = fee_bps;
         cfg.paused = false;
         cfg.bump = ctx.bumps.config;
+        cfg.pending_admin = Pubkey::default();
+        cfg.fee_recipient = ctx.accounts.admin.key();
+        cfg.max_referral_bps = 0;
+        cfg.surplus_share_bps = 0;
+        cfg.oracle_program = Pubkey::default();
+        cfg.max_price_deviation_bps = 0;
+        cfg.max_oracle_staleness_slots = 0;
+        cfg.fee_side = FeeSide::Output;
+        cfg.require_oracle = false;
+        cfg.paused_dexes = 0;
         Ok(())
     }
 
@@ -243,6 +260,333 @@ pub mod aggregator {
         Ok(())
     }
 
+    /// First step of an admin rotation: stages `new_admin`, who must then call
+    /// [`accept_admin`].  Proposing `Pubkey::default()` cancels the transfer.
+    pub fn propose_admin(ctx: Context<Admin>, new_admin: Pubkey) -> Result<()> {
+        let cfg = &mut ctx.accounts.config;
+        cfg.propose_admin(&ctx.accounts.admin.key(), new_admin)?;
+
+        emit!(AdminProposed {
+            admin: cfg.admin,
+            pending_admin: new_admin,
+        });
+        Ok(())
+    }
+
+    /// Second step of an admin rotation, signed by the pending admin.
+    pub fn accept_admin(ctx: Context<Admin>) -> Result<()> {
+        let cfg = &mut ctx.accounts.config;
+        let previous_admin = cfg.accept_admin(&ctx.accounts.admin.key())?;
+
+        emit!(AdminTransferred {
+            previous_admin,
+            new_admin: cfg.admin,
+        });
+        Ok(())
+    }
+
+    /// Creates a fee override for routes into `out_mint`.  Pass
+    /// `Pubkey::default()` as `in_mint` for a tier that applies to any input.
+    pub fn create_fee_tier(
+        ctx: Context<CreateFeeTier>,
+        in_mint: Pubkey,
+        out_mint: Pubkey,
+        fee_bps: u16,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.admin.key() == ctx.accounts.config.admin,
+            AggregatorError::Unauthorized
+        );
+        require!(fee_bps <= 10_000, AggregatorError::InvalidFeeBps);
+        let tier = &mut ctx.accounts.fee_tier;
+        tier.in_mint = in_mint;
+        tier.out_mint = out_mint;
+        tier.fee_bps = fee_bps;
+        tier.bump = ctx.bumps.fee_tier;
+
+        emit!(FeeTierUpdated {
+            in_mint,
+            out_mint,
+            fee_bps,
+        });
+        Ok(())
+    }
+
+    pub fn update_fee_tier(ctx: Context<AdminFeeTier>, fee_bps: u16) -> Result<()> {
+        require!(
+            ctx.accounts.admin.key() == ctx.accounts.config.admin,
+            AggregatorError::Unauthorized
+        );
+        require!(fee_bps <= 10_000, AggregatorError::InvalidFeeBps);
+        let tier = &mut ctx.accounts.fee_tier;
+        tier.fee_bps = fee_bps;
+
+        emit!(FeeTierUpdated {
+            in_mint: tier.in_mint,
+            out_mint: tier.out_mint,
+            fee_bps,
+        });
+        Ok(())
+    }
+
+    /// Removes a fee tier; routes into its mints fall back to `cfg.fee_bps`.
+    pub fn close_fee_tier(ctx: Context<CloseFeeTier>) -> Result<()> {
+        require!(
+            ctx.accounts.admin.key() == ctx.accounts.config.admin,
+            AggregatorError::Unauthorized
+        );
+        emit!(FeeTierClosed {
+            in_mint: ctx.accounts.fee_tier.in_mint,
+            out_mint: ctx.accounts.fee_tier.out_mint,
+        });
+        Ok(())
+    }
+
+    /// Caps the share of the protocol fee a route may pay to its referrer.
+    pub fn set_max_referral_bps(ctx: Context<Admin>, max_referral_bps: u16) -> Result<()> {
+        let cfg = &mut ctx.accounts.config;
+        require!(
+            ctx.accounts.admin.key() == cfg.admin,
+            AggregatorError::Unauthorized
+        );
+        require!(max_referral_bps <= 10_000, AggregatorError::InvalidFeeBps);
+        cfg.max_referral_bps = max_referral_bps;
+        Ok(())
+    }
+
+    /// Registers `owner` as a referrer that routes may pay a fee share to.
+    pub fn register_referrer(ctx: Context<RegisterReferrer>, owner: Pubkey) -> Result<()> {
+        require!(
+            ctx.accounts.admin.key() == ctx.accounts.config.admin,
+            AggregatorError::Unauthorized
+        );
+        let referrer = &mut ctx.accounts.referrer;
+        referrer.owner = owner;
+        referrer.bump = ctx.bumps.referrer;
+
+        emit!(ReferrerRegistered { owner });
+        Ok(())
+    }
+
+    /// Deregisters a referrer; routes can no longer pay it a fee share.
+    pub fn remove_referrer(ctx: Context<RemoveReferrer>) -> Result<()> {
+        require!(
+            ctx.accounts.admin.key() == ctx.accounts.config.admin,
+            AggregatorError::Unauthorized
+        );
+        emit!(ReferrerRemoved {
+            owner: ctx.accounts.referrer.owner,
+        });
+        Ok(())
+    }
+
+    /// Sets the share of positive slippage (output above the route's
+    /// `quoted_out`) charged on top of the base fee, up to
+    /// [`MAX_SURPLUS_SHARE_BPS`].
+    pub fn set_surplus_share_bps(ctx: Context<Admin>, surplus_share_bps: u16) -> Result<()> {
+        let cfg = &mut ctx.accounts.config;
+        require!(
+            ctx.accounts.admin.key() == cfg.admin,
+            AggregatorError::Unauthorized
+        );
+        require!(
+            surplus_share_bps <= MAX_SURPLUS_SHARE_BPS,
+            AggregatorError::InvalidSurplusShareBps
+        );
+        cfg.surplus_share_bps = surplus_share_bps;
+        Ok(())
+    }
+
+    /// Configures the oracle guard routes opt into by passing price feeds, or
+    /// must pass them when `require_oracle` is set; see [`oracle`].
+    pub fn set_oracle_config(
+        ctx: Context<Admin>,
+        oracle_program: Pubkey,
+        max_price_deviation_bps: u16,
+        max_oracle_staleness_slots: u64,
+        require_oracle: bool,
+    ) -> Result<()> {
+        let cfg = &mut ctx.accounts.config;
+        require!(
+            ctx.accounts.admin.key() == cfg.admin,
+            AggregatorError::Unauthorized
+        );
+        require!(
+            max_price_deviation_bps <= 10_000,
+            AggregatorError::InvalidPriceDeviationBps
+        );
+        cfg.oracle_program = oracle_program;
+        cfg.max_price_deviation_bps = max_price_deviation_bps;
+        cfg.max_oracle_staleness_slots = max_oracle_staleness_slots;
+        cfg.require_oracle = require_oracle;
+        Ok(())
+    }
+
+    /// Selects whether routes charge the protocol fee in the output or the
+    /// input mint.
+    pub fn set_fee_side(ctx: Context<Admin>, fee_side: FeeSide) -> Result<()> {
+        let cfg = &mut ctx.accounts.config;
+        require!(
+            ctx.accounts.admin.key() == cfg.admin,
+            AggregatorError::Unauthorized
+        );
+        cfg.fee_side = fee_side;
+        Ok(())
+    }
+
+    /// Moves fee custody to `fee_recipient` (e.g. a multisig or cold wallet)
+    /// without granting it any governance rights.  [`withdraw_fees`] only pays
+    /// out to token accounts owned by the recipient.
+    pub fn set_fee_recipient(ctx: Context<Admin>, fee_recipient: Pubkey) -> Result<()> {
+        let cfg = &mut ctx.accounts.config;
+        require!(
+            ctx.accounts.admin.key() == cfg.admin,
+            AggregatorError::Unauthorized
+        );
+        let previous = cfg.treasury();
+        cfg.fee_recipient = fee_recipient;
+
+        emit!(FeeRecipientUpdated {
+            previous,
+            fee_recipient: cfg.treasury(),
+        });
+        Ok(())
+    }
+
+    /// Sweeps the full balance of one or many fee vaults to token accounts owned
+    /// by the fee recipient.
+    ///
+    /// `remaining_accounts` holds one `[fee_vault, mint, destination]` triple per
+    /// mint; all mints in a call must belong to `token_program`.
+    pub fn withdraw_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawFees<'info>>,
+    ) -> Result<()> {
+        let cfg = &ctx.accounts.config;
+        require!(
+            ctx.accounts.admin.key() == cfg.admin,
+            AggregatorError::Unauthorized
+        );
+        let treasury = cfg.treasury();
+        for triple in fee_vault_triples(ctx.remaining_accounts)? {
+            withdraw_fee_vault(
+                triple,
+                &ctx.accounts.fee_authority,
+                ctx.bumps.fee_authority,
+                treasury,
+                &ctx.accounts.token_program,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Grows a `Config` created by an older program version to [`Config::LEN`].
+    /// New fields are zero-initialised; a no-op when the account is current.
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        let config = ctx.accounts.config.to_account_info();
+        require_keys_eq!(
+            *config.owner,
+            crate::ID,
+            AggregatorError::InvalidConfigAccount
+        );
+
+        let old_len = config.data_len();
+        {
+            let data = config.try_borrow_data()?;
+            require!(
+                data.len() >= 8 + 32 && data[..8] == *Config::DISCRIMINATOR,
+                AggregatorError::InvalidConfigAccount
+            );
+            // Read the admin straight from the old layout: the account is too
+            // short to deserialize as the current `Config`.
+            let admin = Pubkey::try_from(&data[8..40]).unwrap();
+            require_keys_eq!(
+                ctx.accounts.admin.key(),
+                admin,
+                AggregatorError::Unauthorized
+            );
+        }
+        if old_len >= Config::LEN {
+            return Ok(());
+        }
+
+        let rent_due = Rent::get()?
+            .minimum_balance(Config::LEN)
+            .saturating_sub(config.lamports());
+        if rent_due > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.admin.to_account_info(),
+                        to: config.clone(),
+                    },
+                ),
+                rent_due,
+            )?;
+        }
+        config.realloc(Config::LEN, true)?;
+
+        emit!(ConfigMigrated {
+            old_len: old_len as u32,
+            new_len: Config::LEN as u32,
+        });
+        Ok(())
+    }
+
+    /// Creates the [`AdapterRegistry`] PDA seeded with the default program ID of
+    /// every supported DEX.
+    pub fn init_adapter_registry(ctx: Context<InitAdapterRegistry>) -> Result<()> {
+        require!(
+            ctx.accounts.admin.key() == ctx.accounts.config.admin,
+            AggregatorError::Unauthorized
+        );
+        let registry = &mut ctx.accounts.adapter_registry;
+        registry.entries = adapter::SUPPORTED_DEXES
+            .iter()
+            .map(|dex_id| adapter::default_entry(*dex_id))
+            .collect();
+        registry.bump = ctx.bumps.adapter_registry;
+        Ok(())
+    }
+
+    /// Points `dex_id` at `program_id` on this cluster, toggles it and sets the
+    /// extra account owners its CPI may touch.
+    ///
+    /// `enabled` says whether the adapter is usable on this cluster at all; to
+    /// stop routing through a working DEX temporarily use `pause_dex`.
+    pub fn set_adapter(
+        ctx: Context<AdminRegistry>,
+        dex_id: DexId,
+        program_id: Pubkey,
+        enabled: bool,
+        allowed_owners: Vec<Pubkey>,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.admin.key() == ctx.accounts.config.admin,
+            AggregatorError::Unauthorized
+        );
+        require!(
+            allowed_owners.len() <= MAX_ALLOWED_OWNERS,
+            AggregatorError::TooManyAllowedOwners
+        );
+
+        let entry = AdapterEntry {
+            dex_id,
+            program_id,
+            enabled,
+            allowed_owners,
+        };
+        ctx.accounts.adapter_registry.upsert(entry.clone())?;
+
+        emit!(AdapterUpdated {
+            dex_id,
+            program_id,
+            enabled,
+            allowed_owners: entry.allowed_owners,
+        });
+        Ok(())
+    }
+
     pub fn pause(ctx: Context<Admin>) -> Result<()> {
         let cfg = &mut ctx.accounts.config;
         require!(
@@ -262,29 +606,221 @@ pub mod aggregator {
         cfg.paused = false;
         Ok(())
     }
+
+    /// Pauses routing through a single DEX without stopping the whole protocol.
+    pub fn pause_dex(ctx: Context<Admin>, dex_id: DexId) -> Result<()> {
+        set_dex_paused(ctx, dex_id, true)
+    }
+
+    pub fn unpause_dex(ctx: Context<Admin>, dex_id: DexId) -> Result<()> {
+        set_dex_paused(ctx, dex_id, false)
+    }
+}
+
+fn set_dex_paused(ctx: Context<Admin>, dex_id: DexId, paused: bool) -> Result<()> {
+    let cfg = &mut ctx.accounts.config;
+    require!(
+        ctx.accounts.admin.key() == cfg.admin,
+        AggregatorError::Unauthorized
+    );
+    cfg.set_dex_paused(dex_id, paused);
+
+    emit!(DexPauseChanged {
+        dex_id,
+        paused,
+        paused_dexes: cfg.paused_dexes,
+    });
+    Ok(())
+}
+
+/// Splits `withdraw_fees`' remaining accounts into `[fee_vault, mint,
+/// destination]` triples.
+fn fee_vault_triples<'a, 'info>(
+    rem: &'a [AccountInfo<'info>],
+) -> Result<std::slice::ChunksExact<'a, AccountInfo<'info>>> {
+    require!(
+        !rem.is_empty() && rem.len() % 3 == 0,
+        AggregatorError::RemainingAccountsMismatch
+    );
+    Ok(rem.chunks_exact(3))
+}
+
+/// Sweeps the full balance of one fee vault PDA to `treasury`'s token account,
+/// signing as the `fee_authority` PDA.  Returns the amount moved.
+fn withdraw_fee_vault<'info>(
+    triple: &'info [AccountInfo<'info>],
+    fee_authority: &AccountInfo<'info>,
+    fee_authority_bump: u8,
+    treasury: Pubkey,
+    token_program: &AccountInfo<'info>,
+) -> Result<u64> {
+    let vault = InterfaceAccount::<TokenAccount>::try_from(&triple[0])?;
+    let mint = InterfaceAccount::<Mint>::try_from(&triple[1])?;
+    let destination = InterfaceAccount::<TokenAccount>::try_from(&triple[2])?;
+
+    let (vault_address, _) =
+        Pubkey::find_program_address(&[b"fee_vault", mint.key().as_ref()], &crate::ID);
+    require_keys_eq!(vault.key(), vault_address, AggregatorError::InvalidFeeVault);
+    require_keys_eq!(
+        vault.owner,
+        fee_authority.key(),
+        AggregatorError::FeeVaultOwnerMismatch
+    );
+    require_keys_eq!(
+        vault.mint,
+        mint.key(),
+        AggregatorError::FeeVaultMintMismatch
+    );
+    require_keys_eq!(destination.mint, mint.key(), AggregatorError::MintMismatch);
+    require_keys_eq!(
+        destination.owner,
+        treasury,
+        AggregatorError::FeeDestinationMismatch
+    );
+
+    let amount = vault.amount;
+    if amount == 0 {
+        return Ok(0);
+    }
+    let bump = [fee_authority_bump];
+    let seeds: &[&[u8]] = &[b"fee_authority", &bump];
+    let signer = Pubkey::create_program_address(seeds, &crate::ID)
+        .map_err(|_| AggregatorError::FeeVaultOwnerMismatch)?;
+    require_keys_eq!(
+        signer,
+        fee_authority.key(),
+        AggregatorError::FeeVaultOwnerMismatch
+    );
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TransferChecked {
+                from: vault.to_account_info(),
+                mint: mint.to_account_info(),
+                to: destination.to_account_info(),
+                authority: fee_authority.clone(),
+            },
+            &[seeds],
+        ),
+        amount,
+        mint.decimals,
+    )?;
+
+    emit!(FeesWithdrawn {
+        mint: mint.key(),
+        fee_vault: vault.key(),
+        destination: destination.key(),
+        amount,
+    });
+    Ok(amount)
 }
 
 #[derive(Accounts)]
 pub struct RouteAccounts<'info> {
-    /// User
-    #[account(signer)]
+    /// User; writable so it can fund SOL wraps and receive unwrapped lamports.
+    #[account(mut, signer)]
     pub user_authority: Signer<'info>,
 
+    /// CHECK: input tokens; may live under either SPL Token or Token-2022 – the
+    /// legs carry whichever token program their pools need.  With
+    /// `options.wrap_lamports` this is the `[b"wsol_in", user_authority]` PDA the
+    /// route creates and closes; read and checked by `router::execute`.
     #[account(mut)]
-    pub user_source: Account<'info, TokenAccount>,
-    #[account(mut)]
-    pub user_destination: Account<'info, TokenAccount>,
+    pub user_source: UncheckedAccount<'info>,
+    #[account(mut, token::token_program = token_program)]
+    pub user_destination: InterfaceAccount<'info, TokenAccount>,
 
-    /// Fee collector
+    /// Pays rent for the fee vault the first time a fee mint is charged; may
+    /// be the user or a separate rent sponsor.
     #[account(mut)]
-    pub fee_vault: Account<'info, TokenAccount>,
+    pub payer: Signer<'info>,
+
+    /// Output mint; read for its Token-2022 transfer fee configuration.
+    #[account(
+        address = user_destination.mint @ AggregatorError::MintMismatch,
+        mint::token_program = token_program,
+    )]
+    pub destination_mint: InterfaceAccount<'info, Mint>,
+
+    /// Mint the fee is charged in: the output mint, or the input mint when
+    /// `cfg.fee_side` is `FeeSide::Input`.
+    #[account(mint::token_program = fee_token_program)]
+    pub fee_mint: InterfaceAccount<'info, Mint>,
+
+    /// Token program owning `fee_mint`.
+    pub fee_token_program: Interface<'info, TokenInterface>,
+
+    /// Program-owned fee vault for `fee_mint`, created on first use.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [b"fee_vault", fee_mint.key().as_ref()],
+        bump,
+        token::mint = fee_mint,
+        token::authority = fee_authority,
+        token::token_program = fee_token_program,
+    )]
+    pub fee_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: PDA that owns every fee vault; never read.
+    #[account(seeds = [b"fee_authority"], bump)]
+    pub fee_authority: UncheckedAccount<'info>,
+
+    /// CHECK: the `[b"fee_tier", in_mint, out_mint]` PDA, whether or not it
+    /// exists; address and contents are checked by `router::fee_bps`.
+    pub fee_tier: UncheckedAccount<'info>,
+
+    /// CHECK: the any-input `[b"fee_tier", Pubkey::default(), out_mint]` PDA,
+    /// used when `fee_tier` does not exist; checked by `router::fee_bps`.
+    pub default_fee_tier: UncheckedAccount<'info>,
+
+    /// Integrator's token account for `fee_mint`; receives
+    /// `options.referral_bps` of the fee.
+    #[account(
+        mut,
+        token::mint = fee_mint,
+        token::token_program = fee_token_program,
+    )]
+    pub referrer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Registration of `referrer_token_account`'s owner; required with a
+    /// referral share.
+    #[account(seeds = [b"referrer", referrer.owner.as_ref()], bump = referrer.bump)]
+    pub referrer: Option<Account<'info, Referrer>>,
+
+    /// Input mint; only needed for the oracle guard and to wrap SOL, and
+    /// checked against `user_source` by `router::execute`.
+    pub source_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: validated by `oracle::read_price`; pass both feeds to enable the
+    /// oracle guard (mandatory with `cfg.require_oracle`).
+    pub source_price_feed: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: validated by `oracle::read_price`.
+    pub destination_price_feed: Option<UncheckedAccount<'info>>,
 
     /// Global protocol config
     #[account(seeds = [b"config"], bump = config.bump)]
     pub config: Account<'info, Config>,
 
-    /// Programs
-    pub token_program: Program<'info, Token>,
+    /// DEX program IDs and owner whitelists for this cluster
+    #[account(seeds = [b"adapter_registry"], bump = adapter_registry.bump)]
+    pub adapter_registry: Account<'info, AdapterRegistry>,
+
+    /// Token program owning the output mint (SPL Token or Token-2022).
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Token program owning `user_source`; only needed to wrap SOL.
+    pub source_token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// CHECK: the temporary `[b"wsol_out", user_authority]` WSOL account
+    /// `options.unwrap_sol` pays the output through; created and closed by
+    /// `router::unwrap_sol`.
+    #[account(mut)]
+    pub wsol_out: Option<UncheckedAccount<'info>>,
+
+    /// Creates fee vaults and the temporary WSOL accounts.
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
@@ -295,31 +831,179 @@ pub enum DexId {
     SolarCp = 2,
     SolarClmm = 3,
     Invariant = 4,
+    RaydiumAmmV4 = 5,
+    RaydiumCpmm = 6,
+    RaydiumClmm = 7,
+    MeteoraDlmm = 8,
 }
 
 /// Describes a single CPI leg into a downstream AMM.
 ///
 /// The router will forward `account_count` accounts from `ctx.remaining_accounts`
-/// to the selected adapter based on `dex_id`. The adapter will then invoke the
-/// AMM program with `data` as the raw instruction payload.
+/// to the selected adapter based on `dex_id`. The adapter will then encode
+/// `params` into the AMM's swap instruction and invoke the AMM program with it.
+///
+/// Token programs are selected per leg: each leg's account list carries the SPL
+/// Token or Token-2022 program its pool expects, and both are accepted as
+/// account owners by every adapter.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct SwapLeg {
     /// Target DEX adapter.
     pub dex_id: DexId,
     /// Intended input amount for this leg (hint; actual spent is computed post-swap).
+    /// On exact-in routes only the first leg of a branch keeps this amount – later
+    /// legs are rewritten to the measured output of the previous leg.
     pub in_amount: u64,
-    /// Intended minimum output for this leg (hint; final slippage check is applied on net output after fees).
+    /// Minimum output for this leg, enforced against the real balance delta of the
+    /// leg's output token account (the final slippage check is still applied on
+    /// net output after fees).
     pub min_out: u64,
     /// Number of `AccountInfo`s to consume from `remaining_accounts` for this leg.
     pub account_count: u8,
-    /// Raw instruction bytes for the AMM call (Anchor discriminator + Borsh-encoded args for that AMM instruction).
-    pub data: Vec<u8>,
+    /// Typed arguments of the AMM swap; must belong to `dex_id` and agree with
+    /// `in_amount` / `min_out`.
+    pub params: SwapParams,
     /// Expected input SPL mint for this leg (continuity-checked by router).
     pub in_mint: Pubkey,
     /// Expected output SPL mint for this leg (continuity-checked by router).
     pub out_mint: Pubkey,
 }
 
+/// Typed arguments of the swap instruction a leg executes, one variant per AMM
+/// instruction family.  Adapters encode them into the AMM's Anchor instruction
+/// data on-chain, so the router knows the amount, direction and limits of every
+/// CPI it makes.
+///
+/// `amount` is the input when the swap is specified by its input
+/// (`amount_specified_is_input`, `is_base_input`, ...) and the desired output
+/// otherwise; `other_amount_threshold` is then the minimum output or the
+/// maximum input respectively.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum SwapParams {
+    /// Lifinity V2 `swap`.
+    LifinityV2 {
+        amount_in: u64,
+        minimum_amount_out: u64,
+    },
+    /// Whirlpool `swap`, or `swap_v2` when `v2` is set.
+    OrcaWhirlpool {
+        amount: u64,
+        other_amount_threshold: u64,
+        sqrt_price_limit: u128,
+        amount_specified_is_input: bool,
+        a_to_b: bool,
+        v2: bool,
+    },
+    /// Whirlpool `two_hop_swap`, or `two_hop_swap_v2` when `v2` is set.
+    OrcaWhirlpoolTwoHop {
+        amount: u64,
+        other_amount_threshold: u64,
+        amount_specified_is_input: bool,
+        a_to_b_one: bool,
+        a_to_b_two: bool,
+        sqrt_price_limit_one: u128,
+        sqrt_price_limit_two: u128,
+        v2: bool,
+    },
+    /// Solar CP `swap_base_input`, or `swap_base_output` when `base_input` is unset.
+    SolarCp {
+        amount: u64,
+        other_amount_threshold: u64,
+        base_input: bool,
+    },
+    /// Solar CLMM `swap`, or `swap_v2` when `v2` is set.
+    SolarClmm {
+        amount: u64,
+        other_amount_threshold: u64,
+        sqrt_price_limit_x64: u128,
+        is_base_input: bool,
+        v2: bool,
+    },
+    /// Invariant `swap`; the pool enforces no amount threshold of its own.
+    Invariant {
+        x_to_y: bool,
+        amount: u64,
+        by_amount_in: bool,
+        sqrt_price_limit: u128,
+    },
+    /// Raydium AMM v4 `swap_base_in`, or `swap_base_out` when `base_input` is
+    /// unset; `v2` selects the variants without OpenBook accounts.
+    RaydiumAmmV4 {
+        amount: u64,
+        other_amount_threshold: u64,
+        base_input: bool,
+        v2: bool,
+    },
+    /// Raydium CPMM `swap_base_input`, or `swap_base_output` when `base_input` is unset.
+    RaydiumCpmm {
+        amount: u64,
+        other_amount_threshold: u64,
+        base_input: bool,
+    },
+    /// Raydium CLMM `swap_v2`.
+    RaydiumClmm {
+        amount: u64,
+        other_amount_threshold: u64,
+        sqrt_price_limit_x64: u128,
+        is_base_input: bool,
+    },
+    /// Meteora DLMM `swap`, or `swap_exact_out` when `base_input` is unset.
+    MeteoraDlmm {
+        amount: u64,
+        other_amount_threshold: u64,
+        base_input: bool,
+    },
+}
+
+/// Optional per-route behaviour shared by all routing instructions.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct RouteOptions {
+    /// Lamports to wrap before the first leg, at most `user_max_in`.
+    /// `user_source` must then be the `[b"wsol_in", user_authority]` PDA: the
+    /// route creates it as a WSOL account holding this amount and closes it
+    /// after settlement, returning unspent SOL.  Requires `source_mint` (the
+    /// native mint) and `source_token_program`.
+    pub wrap_lamports: u64,
+    /// Pay the net output of a WSOL `user_destination` out as lamports,
+    /// through the temporary `wsol_out` account; WSOL it held before the route
+    /// stays put.  The fee is still taken in WSOL and the slippage check is
+    /// applied before the unwrap.
+    pub unwrap_sol: bool,
+    /// Share of the protocol fee, in basis points of the fee, paid to
+    /// `referrer_token_account`, which must belong to a registered [`Referrer`].
+    /// Capped by `Config::max_referral_bps`.
+    pub referral_bps: u16,
+    /// Output the client quoted, comparable to the measured (pre-fee) output.
+    /// `cfg.surplus_share_bps` of anything above it is charged as a surplus fee
+    /// (never with `Config::fee_side` set to `FeeSide::Input`).  0 defaults to
+    /// the pre-fee output the route's minimum requires; a lower quote is
+    /// rejected.
+    pub quoted_out: u64,
+    /// Last slot the route may execute in; 0 for no limit.
+    pub valid_until_slot: u64,
+    /// Last unix timestamp the route may execute at; 0 for no limit.
+    pub valid_until_ts: i64,
+}
+
+/// Which side of the swap the protocol fee is taken from; set by the admin in
+/// [`Config::fee_side`].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum FeeSide {
+    /// Charged on the measured output after the last leg.
+    #[default]
+    Output,
+    /// Charged on the route's planned input (the first legs' `in_amount`)
+    /// before the first leg executes, and counted towards `user_max_in`.
+    Input,
+}
+
+/// One parallel branch of a split route: a linear chain of [`SwapLeg`]s from the
+/// route's input mint to its output mint.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RouteBranch {
+    pub legs: Vec<SwapLeg>,
+}
+
 // -------------------- Events & Constants --------------------
 
 #[event]
@@ -327,16 +1011,139 @@ pub struct RouteExecuted {
     pub user: Pubkey,
     pub in_mint: Pubkey,
     pub out_mint: Pubkey,
+    /// Input spent, including the fee with `FeeSide::Input`.
     pub total_spent: u64,
+    /// Output credited to the user before the protocol fee (net of Token-2022 transfer fees).
     pub total_out: u64,
+    /// Output sent by the pools before the mint's transfer fee.
+    pub gross_out: u64,
+    /// Output the user keeps after transfer fees, the protocol fee and the surplus fee.
+    pub net_out: u64,
+    /// Token-2022 transfer fees withheld by the output mint.
+    pub transfer_fee_withheld: u64,
+    /// Total fee taken (`protocol_fee + referral_fee`), in the mint given by `fee_side`.
     pub fee_charged: u64,
+    pub fee_side: FeeSide,
+    pub protocol_fee: u64,
+    pub referral_fee: u64,
+    /// Positive-slippage fee charged on top of `fee_charged`.
+    pub surplus_fee: u64,
     pub legs: u8,
+    pub branches: u8,
     pub fee_bps: u16,
 }
 
+/// Emitted once per executed leg with the balance deltas measured around its CPI.
+#[event]
+pub struct LegExecuted {
+    pub leg_index: u8,
+    pub dex_id: DexId,
+    pub in_mint: Pubkey,
+    pub out_mint: Pubkey,
+    pub spent: u64,
+    pub received: u64,
+}
+
+#[event]
+pub struct RouteExactOutExecuted {
+    pub user: Pubkey,
+    pub in_mint: Pubkey,
+    pub out_mint: Pubkey,
+    pub max_in: u64,
+    pub total_spent: u64,
+    pub requested_out: u64,
+    pub delivered_out: u64,
+    pub gross_out: u64,
+    pub transfer_fee_withheld: u64,
+    /// Total fee taken (`protocol_fee + referral_fee`), in the mint given by `fee_side`.
+    pub fee_charged: u64,
+    pub fee_side: FeeSide,
+    pub protocol_fee: u64,
+    pub referral_fee: u64,
+    /// Positive-slippage fee charged on top of `fee_charged`.
+    pub surplus_fee: u64,
+    pub legs: u8,
+    pub fee_bps: u16,
+}
+
+#[event]
+pub struct AdapterUpdated {
+    pub dex_id: DexId,
+    pub program_id: Pubkey,
+    pub enabled: bool,
+    pub allowed_owners: Vec<Pubkey>,
+}
+
+#[event]
+pub struct DexPauseChanged {
+    pub dex_id: DexId,
+    pub paused: bool,
+    /// Full set of paused DEXes after the change, one bit per `DexId`.
+    pub paused_dexes: u32,
+}
+
+#[event]
+pub struct AdminProposed {
+    pub admin: Pubkey,
+    /// `Pubkey::default()` when a pending transfer was cancelled.
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct FeeRecipientUpdated {
+    pub previous: Pubkey,
+    pub fee_recipient: Pubkey,
+}
+
+#[event]
+pub struct FeeTierUpdated {
+    pub in_mint: Pubkey,
+    pub out_mint: Pubkey,
+    pub fee_bps: u16,
+}
+
+#[event]
+pub struct FeeTierClosed {
+    pub in_mint: Pubkey,
+    pub out_mint: Pubkey,
+}
+
+#[event]
+pub struct ReferrerRegistered {
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct ReferrerRemoved {
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub mint: Pubkey,
+    pub fee_vault: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ConfigMigrated {
+    pub old_len: u32,
+    pub new_len: u32,
+}
+
 /// Upper bound on route legs to keep compute and tx size predictable.
 pub const MAX_LEGS: u8 = 10;
 
+/// Upper bound on parallel branches in a split route.
+pub const MAX_BRANCHES: u8 = 4;
+
 // -------------------- Governance Contexts --------------------
 
 #[derive(Accounts)]
@@ -350,7 +1157,7 @@ pub struct InitConfig<'info> {
         payer = admin,
         seeds = [b"config"],
         bump,
-        space = 8 + 32 + 2 + 1 + 1,
+        space = Config::LEN,
     )]
     pub config: Account<'info, Config>,
 
@@ -365,5 +1172,150 @@ pub struct Admin<'info> {
     pub admin: Signer<'info>,
 }
 
+#[derive(Accounts)]
+#[instruction(in_mint: Pubkey, out_mint: Pubkey)]
+pub struct CreateFeeTier<'info> {
+    #[account(mut, signer)]
+    pub admin: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"fee_tier", in_mint.as_ref(), out_mint.as_ref()],
+        bump,
+        space = FeeTier::LEN,
+    )]
+    pub fee_tier: Account<'info, FeeTier>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AdminFeeTier<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [b"fee_tier", fee_tier.in_mint.as_ref(), fee_tier.out_mint.as_ref()],
+        bump = fee_tier.bump,
+    )]
+    pub fee_tier: Account<'info, FeeTier>,
+    #[account(signer)]
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseFeeTier<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"fee_tier", fee_tier.in_mint.as_ref(), fee_tier.out_mint.as_ref()],
+        bump = fee_tier.bump,
+    )]
+    pub fee_tier: Account<'info, FeeTier>,
+    #[account(mut, signer)]
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(owner: Pubkey)]
+pub struct RegisterReferrer<'info> {
+    #[account(mut, signer)]
+    pub admin: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"referrer", owner.as_ref()],
+        bump,
+        space = Referrer::LEN,
+    )]
+    pub referrer: Account<'info, Referrer>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveReferrer<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"referrer", referrer.owner.as_ref()],
+        bump = referrer.bump,
+    )]
+    pub referrer: Account<'info, Referrer>,
+    #[account(mut, signer)]
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(signer)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: PDA signing for the fee vaults.
+    #[account(seeds = [b"fee_authority"], bump)]
+    pub fee_authority: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// Current admin; pays for the extra rent.
+    #[account(mut, signer)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: may predate the current `Config` layout, so it is validated by
+    /// hand (owner, discriminator, admin) in `migrate_config`.
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitAdapterRegistry<'info> {
+    #[account(mut, signer)]
+    pub admin: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"adapter_registry"],
+        bump,
+        space = AdapterRegistry::LEN,
+    )]
+    pub adapter_registry: Account<'info, AdapterRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AdminRegistry<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"adapter_registry"], bump = adapter_registry.bump)]
+    pub adapter_registry: Account<'info, AdapterRegistry>,
+    #[account(signer)]
+    pub admin: Signer<'info>,
+}
+
 #[cfg(test)]
 mod test;
@@ -0,0 +1,121 @@
+//! Oracle price-deviation guard.
+//!
+//! Routes may pass a price feed for each side of the swap.  The realized rate
+//! (`delta_out` per unit of input actually swapped) is then compared against the
+//! rate implied by the two feeds, and the route is rejected when the user got
+//! more than `cfg.max_price_deviation_bps` less than the oracle says it should.
+//! Doing better than the oracle is never rejected.
+//!
+//! The guard is opt-in per route unless `cfg.require_oracle` is set, in which
+//! case routes without feeds are rejected.
+//!
+//! Feeds use the generic [`PriceFeed`] layout and must be owned by
+//! `cfg.oracle_program`, so any publisher (a Pyth relay, an internal keeper, a
+//! mock in tests) can serve them.
+
+use anchor_lang::prelude::*;
+use bytemuck::{Pod, Zeroable};
+
+use crate::error::AggregatorError;
+use crate::router::BPS_DENOMINATOR;
+
+/// Leading bytes of every [`PriceFeed`] account.
+pub const PRICE_FEED_MAGIC: [u8; 8] = *b"aggprice";
+
+/// Price of one whole `mint` token in a common quote currency, as
+/// `price * 10^expo`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct PriceFeed {
+    pub magic: [u8; 8],
+    pub mint: [u8; 32],
+    pub price: i64,
+    pub expo: i32,
+    pub _padding: [u8; 4],
+    /// Slot the price was last updated in.
+    pub publish_slot: u64,
+}
+
+impl PriceFeed {
+    pub const LEN: usize = std::mem::size_of::<PriceFeed>();
+}
+
+/// Reads and validates the feed for `mint` from `feed`.
+pub fn read_price(
+    feed: &AccountInfo<'_>,
+    mint: &Pubkey,
+    oracle_program: &Pubkey,
+    slot: u64,
+    max_staleness_slots: u64,
+) -> Result<PriceFeed> {
+    require_keys_eq!(
+        *feed.owner,
+        *oracle_program,
+        AggregatorError::InvalidPriceFeed
+    );
+    let data = feed.try_borrow_data()?;
+    require!(
+        data.len() >= PriceFeed::LEN,
+        AggregatorError::InvalidPriceFeed
+    );
+    let price: PriceFeed = bytemuck::pod_read_unaligned(&data[..PriceFeed::LEN]);
+    require!(
+        price.magic == PRICE_FEED_MAGIC && price.mint == mint.to_bytes() && price.price > 0,
+        AggregatorError::InvalidPriceFeed
+    );
+    require!(
+        slot.saturating_sub(price.publish_slot) <= max_staleness_slots,
+        AggregatorError::StalePrice
+    );
+    Ok(price)
+}
+
+/// Rejects a fill of `spent` input base units for `received` output base units
+/// that falls more than `max_deviation_bps` short of the oracle rate.  A fill
+/// with either side at 0 has no rate to compare and is rejected.
+pub fn check_deviation(
+    spent: u64,
+    in_decimals: u8,
+    in_price: &PriceFeed,
+    received: u64,
+    out_decimals: u8,
+    out_price: &PriceFeed,
+    max_deviation_bps: u16,
+) -> Result<()> {
+    require!(spent > 0 && received > 0, AggregatorError::ZeroOracleAmount);
+    // Value of each side in quote units, with the powers of ten moved to the
+    // side where they stay non-negative:
+    //   received * p_out * 10^(expo_out - out_decimals) * 10_000
+    //     >= spent * p_in * 10^(expo_in - in_decimals) * (10_000 - max_deviation_bps)
+    let in_scale = in_price.expo as i64 - in_decimals as i64;
+    let out_scale = out_price.expo as i64 - out_decimals as i64;
+    let shift = out_scale - in_scale;
+
+    let mut realized = (received as u128)
+        .checked_mul(out_price.price as u128)
+        .and_then(|v| v.checked_mul(BPS_DENOMINATOR));
+    let mut floor = (spent as u128)
+        .checked_mul(in_price.price as u128)
+        .and_then(|v| v.checked_mul(BPS_DENOMINATOR - max_deviation_bps as u128));
+    let power = 10u128
+        .checked_pow(shift.unsigned_abs() as u32)
+        .ok_or(AggregatorError::NumericalOverflow)?;
+    if shift >= 0 {
+        realized = realized.and_then(|v| v.checked_mul(power));
+    } else {
+        floor = floor.and_then(|v| v.checked_mul(power));
+    }
+
+    let realized = realized.ok_or(AggregatorError::NumericalOverflow)?;
+    let floor = floor.ok_or(AggregatorError::NumericalOverflow)?;
+    if realized < floor {
+        msg!(
+            "Route rate deviates from oracle: {} < {} (max {} bps)",
+            realized,
+            floor,
+            max_deviation_bps
+        );
+        return err!(AggregatorError::PriceDeviationExceeded);
+    }
+    Ok(())
+}
@@ -0,0 +1,1036 @@
+//! Shared execution path for the routing instructions.
+//!
+//! `route`, `route_split` and `route_exact_out` only differ in how the client plans
+//! the legs and in which post-swap limit they enforce.  Everything else – safety gates, leg
+//! dispatch, balance-delta accounting and the protocol fee transfer – lives here
+//! so both entry points stay in lock-step.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::system_program;
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_interface::{
+    self, CloseAccount, InitializeAccount3, TokenAccount, TransferChecked, TransferCheckedWithFee,
+};
+
+use crate::{
+    adapter,
+    error::AggregatorError,
+    oracle,
+    state::{Config, FeeTier, Referrer},
+    transfer_fee, FeeSide, LegExecuted, RouteAccounts, RouteOptions, SwapLeg, MAX_BRANCHES,
+    MAX_LEGS,
+};
+
+/// Basis-point denominator used by every fee calculation.
+pub const BPS_DENOMINATOR: u128 = 10_000;
+
+/// Seed prefix of the temporary WSOL `user_source` a wrapping route creates.
+pub const WSOL_IN_SEED: &[u8] = b"wsol_in";
+/// Seed prefix of the temporary WSOL account an unwrapping route pays through.
+pub const WSOL_OUT_SEED: &[u8] = b"wsol_out";
+
+/// How the amounts encoded in the legs are to be interpreted.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SwapMode {
+    /// Legs specify input amounts. After each hop the router measures the real
+    /// output and rewrites the next leg's amount so it consumes exactly that.
+    ExactIn,
+    /// Legs specify output amounts and were planned backwards by the client;
+    /// they are forwarded untouched.
+    ExactOut,
+}
+
+/// Result of a fully executed route, derived from live token balances.
+pub struct Settlement {
+    /// Mint of `user_source`, which a wrapping route has closed again.
+    pub in_mint: Pubkey,
+    /// Input tokens that actually left `user_source`, including an input-side fee.
+    pub delta_spent: u64,
+    /// Output tokens that actually arrived in `user_destination` (pre-fee). For
+    /// Token-2022 mints with a transfer fee this is already net of the fee the
+    /// mint withheld on the way in.
+    pub delta_out: u64,
+    /// Output the pools sent before the mint's transfer fee (`delta_out` plus
+    /// the fee withheld on each branch's arrival).
+    pub gross_out: u64,
+    /// Total fee taken from `user_source` or `user_destination` (see
+    /// `fee_side`): the protocol's share plus `referral_fee`.
+    pub fee_amount: u64,
+    /// Which mint the fee was charged in (`cfg.fee_side`).
+    pub fee_side: FeeSide,
+    /// Referrer's share of `fee_amount`.
+    pub referral_fee: u64,
+    /// Protocol's share of output above `RouteOptions::quoted_out`, charged on
+    /// top of `fee_amount` and kept entirely by the fee vault.
+    pub surplus_fee: u64,
+    /// Token-2022 transfer fees withheld by the output mint, both on the swap
+    /// output and on an output-side fee transfer.
+    pub transfer_fee_withheld: u64,
+    /// Fee rate applied: the route's fee tier if one exists, else `cfg.fee_bps`.
+    pub fee_bps: u16,
+    /// Number of legs executed across all branches.
+    pub legs: u8,
+}
+
+impl Settlement {
+    /// Net amount left with the user after the protocol and surplus fees.
+    pub fn user_receive(&self) -> Result<u64> {
+        match self.fee_side {
+            FeeSide::Output => Ok(self
+                .delta_out
+                .checked_sub(self.fee_amount)
+                .and_then(|out| out.checked_sub(self.surplus_fee))
+                .ok_or(AggregatorError::NumericalOverflow)?),
+            FeeSide::Input => Ok(self.delta_out),
+        }
+    }
+
+    /// Part of the base fee kept by the protocol vault.
+    pub fn protocol_fee(&self) -> Result<u64> {
+        Ok(self
+            .fee_amount
+            .checked_sub(self.referral_fee)
+            .ok_or(AggregatorError::NumericalOverflow)?)
+    }
+}
+
+/// fee_bps is the fee in basis points (1/100 of a percent)
+/// formula: fee_amount = (amount * fee_bps) / 10_000
+pub fn compute_fee(amount: u64, fee_bps: u16) -> Result<u64> {
+    Ok(((amount as u128 * fee_bps as u128) / BPS_DENOMINATOR)
+        .try_into()
+        .map_err(|_| AggregatorError::NumericalOverflow)?)
+}
+
+/// Rejects a route submitted after the client's deadline.  Zero disables the
+/// corresponding limit; both bounds are inclusive.
+pub fn check_deadline(options: &RouteOptions, slot: u64, unix_timestamp: i64) -> Result<()> {
+    if options.valid_until_slot != 0 {
+        require!(slot <= options.valid_until_slot, AggregatorError::Expired);
+    }
+    if options.valid_until_ts != 0 {
+        require!(
+            unix_timestamp <= options.valid_until_ts,
+            AggregatorError::Expired
+        );
+    }
+    Ok(())
+}
+
+/// Protocol's cut of the output above the client's quote: `share_bps` of
+/// `delta_out - quoted_out`.  A `quoted_out` of 0 (no quote and no minimum
+/// output, see [`pin_quote`]) captures nothing.
+pub fn surplus_fee(delta_out: u64, quoted_out: u64, share_bps: u16) -> Result<u64> {
+    if quoted_out == 0 {
+        return Ok(0);
+    }
+    compute_fee(delta_out.saturating_sub(quoted_out), share_bps)
+}
+
+/// Quote the surplus fee is measured against.
+///
+/// `floor` is the pre-fee output the route's minimum already requires (see
+/// [`required_gross_out`]).  A missing quote defaults to it and a quote below
+/// it is rejected, so output at the floor is never charged a surplus fee.
+///
+/// The quote is the client's own, so the surplus fee is only as binding as the
+/// client makes it: a quote above the real output is charged nothing, and so is
+/// a route with neither a quote nor a minimum output (floor and quote both 0,
+/// see [`surplus_fee`]).
+pub fn pin_quote(quoted_out: u64, floor: u64) -> Result<u64> {
+    if quoted_out == 0 {
+        return Ok(floor);
+    }
+    require!(quoted_out >= floor, AggregatorError::QuoteBelowMinOut);
+    Ok(quoted_out)
+}
+
+/// Pre-fee output a route must deliver for the user to net `net_out` after an
+/// output-side fee at `fee_bps`.  Input-side fees leave the output untouched.
+///
+/// The surplus fee only applies above a quote of at least this amount, so it
+/// never pushes delivery at this floor below `net_out`.
+pub fn required_gross_out(net_out: u64, fee_side: FeeSide, fee_bps: u16) -> Result<u64> {
+    match fee_side {
+        FeeSide::Output => gross_up_for_fee(net_out, fee_bps),
+        FeeSide::Input => Ok(net_out),
+    }
+}
+
+/// Checks that a referral share is paid to a token account of a registered
+/// [`Referrer`] other than the user routing.
+pub fn check_referrer(
+    referrer: Option<&Referrer>,
+    token_account_owner: &Pubkey,
+    user: &Pubkey,
+) -> Result<()> {
+    let referrer = referrer.ok_or(AggregatorError::UnregisteredReferrer)?;
+    require_keys_eq!(
+        *token_account_owner,
+        referrer.owner,
+        AggregatorError::UnregisteredReferrer
+    );
+    require_keys_neq!(*token_account_owner, *user, AggregatorError::SelfReferral);
+    Ok(())
+}
+
+/// Fee rate for a route from `in_mint`; see [`resolve_fee_bps`].
+pub fn fee_bps(accounts: &RouteAccounts<'_>, in_mint: &Pubkey) -> Result<u16> {
+    resolve_fee_bps(
+        &accounts.fee_tier,
+        &accounts.default_fee_tier,
+        in_mint,
+        &accounts.user_destination.mint,
+        accounts.config.fee_bps,
+    )
+}
+
+/// Fee rate for a route from `in_mint` to `out_mint`: the pair's [`FeeTier`]
+/// if it exists, else the any-input tier for `out_mint` if that exists, else
+/// `default_bps`.
+///
+/// Both candidate PDAs must be passed whether or not they exist, so a route
+/// cannot skip a tier that applies to it.
+pub fn resolve_fee_bps<'info>(
+    pair_tier: &AccountInfo<'info>,
+    any_input_tier: &AccountInfo<'info>,
+    in_mint: &Pubkey,
+    out_mint: &Pubkey,
+    default_bps: u16,
+) -> Result<u16> {
+    let candidates = [(pair_tier, *in_mint), (any_input_tier, Pubkey::default())];
+    for (account, tier_in_mint) in candidates {
+        if let Some(tier) = load_fee_tier(account, &tier_in_mint, out_mint)? {
+            require!(
+                tier.applies_to(in_mint, out_mint),
+                AggregatorError::FeeTierMismatch
+            );
+            return Ok(tier.fee_bps);
+        }
+    }
+    Ok(default_bps)
+}
+
+/// Tier stored at the `[b"fee_tier", in_mint, out_mint]` PDA, or `None` if that
+/// account was never created or has been closed.
+pub fn load_fee_tier(
+    account: &AccountInfo<'_>,
+    in_mint: &Pubkey,
+    out_mint: &Pubkey,
+) -> Result<Option<FeeTier>> {
+    let (address, _) = Pubkey::find_program_address(
+        &[b"fee_tier", in_mint.as_ref(), out_mint.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(account.key(), address, AggregatorError::FeeTierMismatch);
+    if *account.owner != crate::ID || account.data_is_empty() {
+        return Ok(None);
+    }
+    let data = account.try_borrow_data()?;
+    Ok(Some(FeeTier::try_deserialize(&mut &data[..])?))
+}
+
+/// Smallest gross output whose post-fee remainder is at least `net`.
+///
+/// This is the inverse of [`compute_fee`]: `gross - compute_fee(gross) >= net`.
+/// A `net` of 0 needs no output at any rate, including a 100% fee.
+pub fn gross_up_for_fee(net: u64, fee_bps: u16) -> Result<u64> {
+    if net == 0 {
+        return Ok(0);
+    }
+    require!(fee_bps < 10_000, AggregatorError::InvalidFeeBps);
+    // The fee is floored, so `gross - fee == ceil(gross * keep_bps / 10_000)`.
+    // The smallest gross with that value >= net is floor((net - 1) * 10_000 / keep_bps) + 1.
+    let keep_bps = BPS_DENOMINATOR - fee_bps as u128;
+    let gross = (net as u128 - 1) * BPS_DENOMINATOR / keep_bps + 1;
+    Ok(gross
+        .try_into()
+        .map_err(|_| AggregatorError::NumericalOverflow)?)
+}
+
+/// Validates an exact-output plan walking the legs backwards from the destination.
+///
+/// The last leg must promise at least `required_out` and every earlier leg must
+/// promise at least what the following leg intends to consume.
+pub fn plan_exact_out(legs: &[SwapLeg], required_out: u64) -> Result<()> {
+    let mut needed = required_out;
+    for leg in legs.iter().rev() {
+        require!(leg.min_out >= needed, AggregatorError::ExactOutPlanMismatch);
+        needed = leg.in_amount;
+    }
+    Ok(())
+}
+
+/// Input the route plans to swap: the sum of each branch's first-leg
+/// `in_amount` (on exact-out routes, the most each branch may spend).  An
+/// input-side fee is charged on this before any leg runs.
+pub fn planned_input(branches: &[&[SwapLeg]]) -> Result<u64> {
+    branches
+        .iter()
+        .filter_map(|legs| legs.first())
+        .try_fold(0u64, |total, leg| total.checked_add(leg.in_amount))
+        .ok_or(AggregatorError::NumericalOverflow.into())
+}
+
+/// Input-side fee on `planned_in`, drawn from `user_source` before the first
+/// leg; swap amount plus fee must fit within `user_max_in`.
+pub fn input_fee(planned_in: u64, fee_bps: u16, user_max_in: u64) -> Result<u64> {
+    let fee_amount = compute_fee(planned_in, fee_bps)?;
+    let total_spent = planned_in
+        .checked_add(fee_amount)
+        .ok_or(AggregatorError::NumericalOverflow)?;
+    require!(
+        total_spent <= user_max_in,
+        AggregatorError::TooManyTokensSpent
+    );
+    Ok(fee_amount)
+}
+
+/// Checks the shape of a (possibly split) route before anything is dispatched.
+///
+/// Every branch is a linear chain that starts at `source_mint` and ends at
+/// `destination_mint`, with mint continuity enforced between consecutive legs.
+/// Returns the total number of legs across all branches.
+pub fn validate_branches(
+    branches: &[&[SwapLeg]],
+    source_mint: Pubkey,
+    destination_mint: Pubkey,
+) -> Result<usize> {
+    require!(!branches.is_empty(), AggregatorError::NoLegs);
+    require!(
+        branches.len() <= MAX_BRANCHES as usize,
+        AggregatorError::TooManyBranches
+    );
+
+    let mut total_legs = 0usize;
+    for legs in branches {
+        // Empty branch not allowed – protects against accidental fee burn
+        require!(!legs.is_empty(), AggregatorError::NoLegs);
+        total_legs += legs.len();
+
+        // Ensure first leg consumes the tokens provided in `user_source`
+        let mut prev_out_mint = source_mint;
+        for leg in legs.iter() {
+            // Enforce mint continuity across legs (out_mint of previous == in_mint of next)
+            require_keys_eq!(leg.in_mint, prev_out_mint, AggregatorError::MintMismatch);
+            prev_out_mint = leg.out_mint;
+        }
+
+        // Ensure every branch converges on the `user_destination` mint
+        require_keys_eq!(
+            prev_out_mint,
+            destination_mint,
+            AggregatorError::MintMismatch
+        );
+    }
+
+    // Bound the number of legs across the whole route
+    require!(
+        total_legs <= MAX_LEGS as usize,
+        AggregatorError::TooManyLegs
+    );
+
+    Ok(total_legs)
+}
+
+/// Checks that a branch swaps through the route's own token accounts: its
+/// first leg spends from `user_source`, every later leg from the account the
+/// previous leg delivered to, and its last leg delivers to `user_destination`.
+///
+/// Without this a leg could trade between other accounts of the user, leaving
+/// the measured spend and output – and every fee and limit based on them – at 0.
+/// `first_leg` is the route-wide index of the branch's first leg.
+pub fn check_branch_accounts(
+    fills: &[adapter::LegFill],
+    first_leg: u8,
+    user_source: &Pubkey,
+    user_destination: &Pubkey,
+) -> Result<()> {
+    let mut expected_input = *user_source;
+    for (leg_index, fill) in (first_leg..).zip(fills) {
+        if fill.input != expected_input {
+            msg!(
+                "Leg {} spends from {} instead of {}",
+                leg_index,
+                fill.input,
+                expected_input
+            );
+            return err!(AggregatorError::LegInputMismatch);
+        }
+        expected_input = fill.output;
+    }
+    if expected_input != *user_destination {
+        msg!(
+            "Branch delivers to {} instead of {}",
+            expected_input,
+            user_destination
+        );
+        return err!(AggregatorError::LegOutputMismatch);
+    }
+    Ok(())
+}
+
+/// Runs the full route: safety gates, leg dispatch, accounting and fee transfer.
+///
+/// Limits on the *output* side are left to the caller because they differ
+/// between exact-in and exact-out routing; `min_out` is the net output the
+/// caller will require.  It pins the surplus quote and, on exact-out routes,
+/// the output the legs must plan for.
+pub fn execute<'info>(
+    accounts: &mut RouteAccounts<'_>,
+    remaining: &[AccountInfo<'info>],
+    branches: &[&[SwapLeg]],
+    mode: SwapMode,
+    user_max_in: u64,
+    min_out: u64,
+    options: &RouteOptions,
+) -> Result<Settlement> {
+    let mut rem_accs = remaining;
+
+    // Governance config
+    let cfg = &accounts.config;
+
+    // ------------------------------------------------------------------
+    // Global safety gates & basic route sanity checks
+    // ------------------------------------------------------------------
+    // 1) Protocol pause switch and the client's deadline
+    require!(!cfg.paused, AggregatorError::Paused);
+    if options.valid_until_slot != 0 || options.valid_until_ts != 0 {
+        let clock = Clock::get()?;
+        check_deadline(options, clock.slot, clock.unix_timestamp)?;
+    }
+
+    // 2) Ensure token accounts are controlled by the caller (fail-fast).  A
+    //    wrapping route first creates `user_source` from the user's lamports.
+    if options.wrap_lamports > 0 {
+        create_wsol_source(accounts, options.wrap_lamports, user_max_in)?;
+    }
+    let source = read_token_account(&accounts.user_source)?;
+    require_keys_eq!(
+        source.owner,
+        accounts.user_authority.key(),
+        AggregatorError::Unauthorized
+    );
+    require_keys_eq!(
+        accounts.user_destination.owner,
+        accounts.user_authority.key(),
+        AggregatorError::Unauthorized
+    );
+
+    // 3) Route shape: non-empty, bounded, mint-continuous branches from
+    //    `user_source` to `user_destination`.
+    let total_legs = validate_branches(branches, source.mint, accounts.user_destination.mint)?;
+
+    // 4) Referral share: bounded by config and paid to a supplied account.
+    require!(
+        options.referral_bps <= cfg.max_referral_bps,
+        AggregatorError::ReferralBpsTooHigh
+    );
+    if options.referral_bps > 0 {
+        let referrer_token_account = accounts
+            .referrer_token_account
+            .as_ref()
+            .ok_or(AggregatorError::MissingOptionalAccount)?;
+        check_referrer(
+            accounts.referrer.as_deref(),
+            &referrer_token_account.owner,
+            &accounts.user_authority.key(),
+        )?;
+    }
+
+    // 5) The fee vault must hold the mint the fee is charged in.
+    let fee_side = cfg.fee_side;
+    let fee_mint = match fee_side {
+        FeeSide::Output => accounts.user_destination.mint,
+        FeeSide::Input => source.mint,
+    };
+    require_keys_eq!(
+        accounts.fee_mint.key(),
+        fee_mint,
+        AggregatorError::FeeVaultMintMismatch
+    );
+    let fee_bps = fee_bps(accounts, &source.mint)?;
+    let required_out = required_gross_out(min_out, fee_side, fee_bps)?;
+    let quoted_out = pin_quote(options.quoted_out, required_out)?;
+
+    // 6) Exact-out plans must deliver `min_out` after the fee.
+    if mode == SwapMode::ExactOut {
+        for legs in branches {
+            plan_exact_out(legs, required_out)?;
+        }
+    }
+
+    // ------------------------------------------------------------------
+    // Snapshot balances – we'll use the deltas later to compute the exact
+    // amount spent/received and to implement slippage + fee checks.
+    // ------------------------------------------------------------------
+    let pre_src_balance = source.amount;
+    let pre_dest_balance = accounts.user_destination.amount;
+
+    // Fee-on-input is taken before the first leg, on the input the route plans
+    // to swap, so it counts towards `user_max_in` through the measured spend.
+    let planned_in = planned_input(branches)?;
+    let input_fee = match fee_side {
+        FeeSide::Input => {
+            let fee_amount = input_fee(planned_in, fee_bps, user_max_in)?;
+            let source = accounts.user_source.to_account_info();
+            let (referral_fee, _) = collect_fee(accounts, source, fee_amount, 0, options)?;
+            Some((fee_amount, referral_fee))
+        }
+        FeeSide::Output => None,
+    };
+
+    // Branches execute one after the other; the remaining accounts of all legs
+    // are laid out back-to-back in branch order.
+    let mut leg_index: u8 = 0;
+    // Amount each branch's final leg credited to `user_destination`.
+    let mut branch_outputs = Vec::with_capacity(branches.len());
+    for legs in branches {
+        // Output actually produced by the previous leg of this branch.
+        let mut carried: Option<u64> = None;
+        let mut branch_received = 0;
+        let first_leg = leg_index;
+        let mut fills = Vec::with_capacity(legs.len());
+
+        for planned in legs.iter() {
+            let mut leg = planned.clone();
+            // Exact-in: the next hop consumes exactly what the previous one produced.
+            if let Some(amount) = carried {
+                adapter::set_in_amount(&mut leg, amount)?;
+            }
+
+            // The adapter reports real balance deltas of the leg's own token accounts.
+            let fill = adapter::dispatch(&leg, rem_accs, &accounts.adapter_registry, cfg)?;
+            let (spent, received, consumed) = (fill.spent, fill.received, fill.consumed);
+            // Defense-in-depth: adapter must consume exactly what the leg declares
+            require!(
+                consumed == leg.account_count as usize,
+                AggregatorError::RemainingAccountsMismatch
+            );
+            require!(
+                consumed <= rem_accs.len(),
+                AggregatorError::RemainingAccountsMismatch
+            );
+            rem_accs = &rem_accs[consumed..];
+
+            // Per-leg slippage: pinpoint the pool that under-delivered.
+            if received < leg.min_out {
+                msg!(
+                    "Leg {} ({:?}) slipped: received {} < min_out {}",
+                    leg_index,
+                    leg.dex_id,
+                    received,
+                    leg.min_out
+                );
+                return err!(AggregatorError::LegSlippageExceeded);
+            }
+
+            emit!(LegExecuted {
+                leg_index,
+                dex_id: leg.dex_id,
+                in_mint: leg.in_mint,
+                out_mint: leg.out_mint,
+                spent,
+                received,
+            });
+
+            if mode == SwapMode::ExactIn {
+                carried = Some(received);
+            }
+            branch_received = received;
+            fills.push(fill);
+            leg_index += 1;
+        }
+        // The measured deltas only describe the route if the legs chain
+        // `user_source` to `user_destination` through each other's accounts.
+        check_branch_accounts(
+            &fills,
+            first_leg,
+            &accounts.user_source.key(),
+            &accounts.user_destination.key(),
+        )?;
+        branch_outputs.push(branch_received);
+    }
+
+    // ------------------------------------------------------------------
+    // Post-execution accounting & user-side limits
+    // ------------------------------------------------------------------
+    // Reload destination to fetch post-swap balance
+    accounts.user_destination.reload()?;
+    let post_dest_balance = accounts.user_destination.amount;
+    // Re-read source to compute how many tokens were actually spent
+    let post_src_balance = read_token_account(&accounts.user_source)?.amount;
+    let delta_spent = pre_src_balance
+        .checked_sub(post_src_balance)
+        .ok_or(AggregatorError::NumericalOverflow)?;
+    require!(
+        delta_spent <= user_max_in,
+        AggregatorError::TooManyTokensSpent
+    );
+    let delta_out = post_dest_balance
+        .checked_sub(pre_dest_balance)
+        .ok_or(AggregatorError::NumericalOverflow)?;
+
+    // What the legs spent, excluding an input-side fee.  The fee was charged
+    // on `planned_in`, so the legs may not spend more than that.
+    let swap_spent = match input_fee {
+        Some((fee_amount, _)) => {
+            let swap_spent = delta_spent
+                .checked_sub(fee_amount)
+                .ok_or(AggregatorError::NumericalOverflow)?;
+            require!(
+                swap_spent <= planned_in,
+                AggregatorError::TooManyTokensSpent
+            );
+            swap_spent
+        }
+        None => delta_spent,
+    };
+
+    // Oracle guard on the rate the legs achieved, before any fee.
+    check_oracle(accounts, &source.mint, swap_spent, delta_out)?;
+
+    // ------------------------------------------------------------------
+    // Output-side fee calculation & transfer – based on *real* output to
+    // make fee-exploitation (e.g. via hints) impossible.
+    // ------------------------------------------------------------------
+    let out_fee_config = transfer_fee::config(&accounts.destination_mint.to_account_info())?;
+    let epoch = match out_fee_config {
+        Some(_) => Clock::get()?.epoch,
+        None => 0,
+    };
+    // Each branch's final transfer was charged separately.
+    let inbound_withheld =
+        transfer_fee::fee_withheld_from_each(out_fee_config.as_ref(), epoch, &branch_outputs)?;
+
+    let (fee_amount, referral_fee, surplus_fee, fee_withheld) = match input_fee {
+        // Positive slippage is only captured when fees are taken in the output mint.
+        Some((fee_amount, referral_fee)) => (fee_amount, referral_fee, 0, 0),
+        None => {
+            // The protocol fee is charged on what the user actually netted, i.e.
+            // after any Token-2022 transfer fee withheld on the way in.
+            let fee_amount = compute_fee(delta_out, fee_bps)?;
+            let surplus_fee =
+                surplus_fee(delta_out, quoted_out, accounts.config.surplus_share_bps)?;
+            let destination = accounts.user_destination.to_account_info();
+            let (referral_fee, withheld) =
+                collect_fee(accounts, destination, fee_amount, surplus_fee, options)?;
+            (fee_amount, referral_fee, surplus_fee, withheld)
+        }
+    };
+
+    // A temporary WSOL source returns its unspent lamports and rent to the user.
+    if options.wrap_lamports > 0 {
+        close_wsol_source(accounts)?;
+    }
+
+    Ok(Settlement {
+        in_mint: source.mint,
+        delta_spent,
+        delta_out,
+        gross_out: delta_out
+            .checked_add(inbound_withheld)
+            .ok_or(AggregatorError::NumericalOverflow)?,
+        fee_amount,
+        fee_side,
+        referral_fee,
+        surplus_fee,
+        fee_bps,
+        transfer_fee_withheld: inbound_withheld
+            .checked_add(fee_withheld)
+            .ok_or(AggregatorError::NumericalOverflow)?,
+        legs: total_legs as u8,
+    })
+}
+
+/// Whether the oracle guard runs for a route passing the given price feeds:
+/// with both, or with neither unless `cfg.require_oracle` is set.
+pub fn oracle_guard_enabled(
+    cfg: &Config,
+    source_feed: bool,
+    destination_feed: bool,
+) -> Result<bool> {
+    match (source_feed, destination_feed) {
+        (true, true) => Ok(true),
+        (false, false) => {
+            require!(!cfg.require_oracle, AggregatorError::OracleRequired);
+            Ok(false)
+        }
+        // Half a guard is a client bug, not an opt-out.
+        _ => err!(AggregatorError::MissingOptionalAccount),
+    }
+}
+
+/// Compares the realized rate against the route's price feeds, if supplied.
+fn check_oracle(
+    accounts: &RouteAccounts<'_>,
+    in_mint: &Pubkey,
+    spent: u64,
+    received: u64,
+) -> Result<()> {
+    let enabled = oracle_guard_enabled(
+        &accounts.config,
+        accounts.source_price_feed.is_some(),
+        accounts.destination_price_feed.is_some(),
+    )?;
+    let (true, Some(source_feed), Some(destination_feed)) = (
+        enabled,
+        &accounts.source_price_feed,
+        &accounts.destination_price_feed,
+    ) else {
+        return Ok(());
+    };
+    let source_mint = accounts
+        .source_mint
+        .as_ref()
+        .ok_or(AggregatorError::MissingOptionalAccount)?;
+    require_keys_eq!(source_mint.key(), *in_mint, AggregatorError::MintMismatch);
+
+    let cfg = &accounts.config;
+    require!(
+        cfg.oracle_program != Pubkey::default(),
+        AggregatorError::InvalidPriceFeed
+    );
+    let slot = Clock::get()?.slot;
+    let in_price = oracle::read_price(
+        source_feed,
+        in_mint,
+        &cfg.oracle_program,
+        slot,
+        cfg.max_oracle_staleness_slots,
+    )?;
+    let out_price = oracle::read_price(
+        destination_feed,
+        &accounts.user_destination.mint,
+        &cfg.oracle_program,
+        slot,
+        cfg.max_oracle_staleness_slots,
+    )?;
+    oracle::check_deviation(
+        spent,
+        source_mint.decimals,
+        &in_price,
+        received,
+        accounts.destination_mint.decimals,
+        &out_price,
+        cfg.max_price_deviation_bps,
+    )
+}
+
+/// Whether `mint` is the wrapped-SOL mint of either token program.
+pub fn is_native_mint(mint: &Pubkey) -> bool {
+    *mint == spl_token::native_mint::ID || *mint == spl_token_2022::native_mint::ID
+}
+
+/// Reads `user_source`, which is unchecked so a wrapping route can create it.
+pub fn read_token_account(account: &AccountInfo<'_>) -> Result<TokenAccount> {
+    require!(
+        adapter::is_token_program(account.owner),
+        AggregatorError::InvalidTokenAccount
+    );
+    let data = account.try_borrow_data()?;
+    TokenAccount::try_deserialize(&mut &data[..])
+}
+
+/// Lamports the temporary WSOL `user_source` is funded with: its rent plus
+/// `wrap_lamports`, which may not exceed what the route is allowed to spend.
+pub fn wsol_source_lamports(rent: u64, wrap_lamports: u64, user_max_in: u64) -> Result<u64> {
+    require!(
+        wrap_lamports <= user_max_in,
+        AggregatorError::WrapExceedsMaxIn
+    );
+    Ok(rent
+        .checked_add(wrap_lamports)
+        .ok_or(AggregatorError::NumericalOverflow)?)
+}
+
+/// Creates `user_source` as the temporary `[WSOL_IN_SEED, user_authority]`
+/// account holding `wrap_lamports` of the user's SOL as WSOL.
+fn create_wsol_source(
+    accounts: &RouteAccounts<'_>,
+    wrap_lamports: u64,
+    user_max_in: u64,
+) -> Result<()> {
+    let source_mint = accounts
+        .source_mint
+        .as_ref()
+        .ok_or(AggregatorError::MissingOptionalAccount)?;
+    let source_token_program = accounts
+        .source_token_program
+        .as_ref()
+        .ok_or(AggregatorError::MissingOptionalAccount)?;
+    require!(
+        is_native_mint(&source_mint.key()),
+        AggregatorError::NotNativeMint
+    );
+    require_keys_eq!(
+        *source_mint.to_account_info().owner,
+        source_token_program.key(),
+        AggregatorError::InvalidProgramId
+    );
+
+    let rent = Rent::get()?.minimum_balance(spl_token::state::Account::LEN);
+    create_wsol_account(
+        accounts,
+        &accounts.user_source,
+        WSOL_IN_SEED,
+        &source_mint.to_account_info(),
+        &source_token_program.to_account_info(),
+        wsol_source_lamports(rent, wrap_lamports, user_max_in)?,
+    )
+}
+
+/// Closes the temporary WSOL `user_source`, returning the SOL the legs did not
+/// spend and its rent to the user.
+fn close_wsol_source(accounts: &RouteAccounts<'_>) -> Result<()> {
+    let source_token_program = accounts
+        .source_token_program
+        .as_ref()
+        .ok_or(AggregatorError::MissingOptionalAccount)?;
+    token_interface::close_account(CpiContext::new(
+        source_token_program.to_account_info(),
+        CloseAccount {
+            account: accounts.user_source.to_account_info(),
+            destination: accounts.user_authority.to_account_info(),
+            authority: accounts.user_authority.to_account_info(),
+        },
+    ))
+}
+
+/// Pays `amount` of a WSOL `user_destination` out as lamports through the
+/// temporary `[WSOL_OUT_SEED, user_authority]` account, leaving any WSOL the
+/// user held before the route in place.
+///
+/// Must run after the slippage check so the limit applies to token amounts.
+pub fn unwrap_sol(accounts: &RouteAccounts<'_>, amount: u64) -> Result<()> {
+    require!(
+        is_native_mint(&accounts.user_destination.mint),
+        AggregatorError::NotNativeMint
+    );
+    let wsol_out = accounts
+        .wsol_out
+        .as_ref()
+        .ok_or(AggregatorError::MissingOptionalAccount)?;
+
+    let rent = Rent::get()?.minimum_balance(spl_token::state::Account::LEN);
+    create_wsol_account(
+        accounts,
+        wsol_out,
+        WSOL_OUT_SEED,
+        &accounts.destination_mint.to_account_info(),
+        &accounts.token_program.to_account_info(),
+        rent,
+    )?;
+    token_interface::transfer_checked(
+        CpiContext::new(
+            accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: accounts.user_destination.to_account_info(),
+                mint: accounts.destination_mint.to_account_info(),
+                to: wsol_out.to_account_info(),
+                authority: accounts.user_authority.to_account_info(),
+            },
+        ),
+        amount,
+        accounts.destination_mint.decimals,
+    )?;
+    token_interface::close_account(CpiContext::new(
+        accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: wsol_out.to_account_info(),
+            destination: accounts.user_authority.to_account_info(),
+            authority: accounts.user_authority.to_account_info(),
+        },
+    ))
+}
+
+/// Creates the WSOL token account at the `[seed, user_authority]` PDA, funded
+/// by the user with `lamports` (rent included) and owned by the user.
+fn create_wsol_account<'info>(
+    accounts: &RouteAccounts<'info>,
+    account: &AccountInfo<'info>,
+    seed: &[u8],
+    mint: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    lamports: u64,
+) -> Result<()> {
+    let user = accounts.user_authority.key();
+    let (address, bump) = Pubkey::find_program_address(&[seed, user.as_ref()], &crate::ID);
+    require_keys_eq!(account.key(), address, AggregatorError::InvalidWsolAccount);
+    let bump = [bump];
+    let seeds: &[&[u8]] = &[seed, user.as_ref(), &bump];
+    let space = spl_token::state::Account::LEN as u64;
+    let system = accounts.system_program.to_account_info();
+    let payer = accounts.user_authority.to_account_info();
+
+    let current = account.lamports();
+    if current == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                system.clone(),
+                system_program::CreateAccount {
+                    from: payer,
+                    to: account.clone(),
+                },
+                &[seeds],
+            ),
+            lamports,
+            space,
+            token_program.key,
+        )?;
+    } else {
+        // Someone pre-funded the address: top it up and take it over.
+        let top_up = lamports.saturating_sub(current);
+        if top_up > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system.clone(),
+                    system_program::Transfer {
+                        from: payer,
+                        to: account.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                system.clone(),
+                system_program::Allocate {
+                    account_to_allocate: account.clone(),
+                },
+                &[seeds],
+            ),
+            space,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                system,
+                system_program::Assign {
+                    account_to_assign: account.clone(),
+                },
+                &[seeds],
+            ),
+            token_program.key,
+        )?;
+    }
+
+    token_interface::initialize_account3(CpiContext::new(
+        token_program.clone(),
+        InitializeAccount3 {
+            account: account.clone(),
+            mint: mint.clone(),
+            authority: accounts.user_authority.to_account_info(),
+        },
+    ))
+}
+
+/// Splits `fee_amount` between the protocol and the referrer and moves it
+/// from `from` (`user_source` or `user_destination`) into the fee vault and the
+/// referrer's token account, both denominated in `fee_mint`.  `surplus_fee`
+/// goes to the fee vault alongside the protocol's share.
+///
+/// Returns the referral share and the Token-2022 transfer fees `fee_mint`
+/// withheld on the way; those are asserted via `transfer_checked_with_fee`.
+fn collect_fee<'info>(
+    accounts: &RouteAccounts<'info>,
+    from: AccountInfo<'info>,
+    fee_amount: u64,
+    surplus_fee: u64,
+    options: &RouteOptions,
+) -> Result<(u64, u64)> {
+    // The vault address, mint and authority are pinned by the account
+    // constraints; keep the mint check as defence in depth.
+    require_keys_eq!(
+        accounts.fee_vault.mint,
+        accounts.fee_mint.key(),
+        AggregatorError::FeeVaultMintMismatch
+    );
+
+    let referral_fee = compute_fee(fee_amount, options.referral_bps)?;
+    let protocol_fee = (fee_amount - referral_fee)
+        .checked_add(surplus_fee)
+        .ok_or(AggregatorError::NumericalOverflow)?;
+
+    let fee_config = transfer_fee::config(&accounts.fee_mint.to_account_info())?;
+    let epoch = match fee_config {
+        Some(_) => Clock::get()?.epoch,
+        None => 0,
+    };
+    let protocol_withheld = transfer_fee::fee_on(fee_config.as_ref(), epoch, protocol_fee)?;
+    let referral_withheld = transfer_fee::fee_on(fee_config.as_ref(), epoch, referral_fee)?;
+
+    transfer_fee_to(
+        accounts,
+        from.clone(),
+        accounts.fee_vault.to_account_info(),
+        protocol_fee,
+        fee_config.map(|_| protocol_withheld),
+    )?;
+
+    if referral_fee > 0 {
+        let referrer = accounts
+            .referrer_token_account
+            .as_ref()
+            .ok_or(AggregatorError::MissingOptionalAccount)?;
+        transfer_fee_to(
+            accounts,
+            from,
+            referrer.to_account_info(),
+            referral_fee,
+            fee_config.map(|_| referral_withheld),
+        )?;
+    }
+
+    Ok((
+        referral_fee,
+        protocol_withheld
+            .checked_add(referral_withheld)
+            .ok_or(AggregatorError::NumericalOverflow)?,
+    ))
+}
+
+/// Transfers `amount` of `fee_mint` from the user's `from` account to `to`.
+fn transfer_fee_to<'info>(
+    accounts: &RouteAccounts<'info>,
+    from: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    amount: u64,
+    transfer_fee: Option<u64>,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    if let Some(fee) = transfer_fee {
+        let cpi_ctx = TransferCheckedWithFee {
+            token_program_id: accounts.fee_token_program.to_account_info(),
+            source: from,
+            mint: accounts.fee_mint.to_account_info(),
+            destination: to,
+            authority: accounts.user_authority.to_account_info(),
+        };
+        token_interface::transfer_checked_with_fee(
+            CpiContext::new(accounts.fee_token_program.to_account_info(), cpi_ctx),
+            amount,
+            accounts.fee_mint.decimals,
+            fee,
+        )?;
+    } else {
+        let cpi_ctx = TransferChecked {
+            from,
+            mint: accounts.fee_mint.to_account_info(),
+            to,
+            authority: accounts.user_authority.to_account_info(),
+        };
+        token_interface::transfer_checked(
+            CpiContext::new(accounts.fee_token_program.to_account_info(), cpi_ctx),
+            amount,
+            accounts.fee_mint.decimals,
+        )?;
+    }
+
+    Ok(())
+}
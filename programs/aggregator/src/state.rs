@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::{adapter::is_token_program, error::AggregatorError, DexId, FeeSide};
+
 #[account]
 #[derive(Debug)]
 pub struct Config {
@@ -7,4 +9,171 @@ pub struct Config {
     pub fee_bps: u16,
     pub paused: bool,
     pub bump: u8,
+    /// Admin proposed via `propose_admin`; `Pubkey::default()` when none.
+    pub pending_admin: Pubkey,
+    /// Owner of the token accounts `withdraw_fees` may pay out to;
+    /// `Pubkey::default()` falls back to `admin`.
+    pub fee_recipient: Pubkey,
+    /// Upper bound on `RouteOptions::referral_bps`; 0 disables referrals.
+    pub max_referral_bps: u16,
+    /// Share of output above `RouteOptions::quoted_out` taken as a surplus fee.
+    pub surplus_share_bps: u16,
+    /// Program that must own the price feeds passed to routes.
+    pub oracle_program: Pubkey,
+    /// Largest shortfall of the realized rate against the oracle rate.
+    pub max_price_deviation_bps: u16,
+    /// Oldest price, in slots, the oracle guard accepts.
+    pub max_oracle_staleness_slots: u64,
+    /// Mint routes charge the protocol fee in.
+    pub fee_side: FeeSide,
+    /// Reject routes that do not pass price feeds for the oracle guard.
+    pub require_oracle: bool,
+    /// Per-DEX pause switches, one bit per [`DexId`] discriminant.
+    pub paused_dexes: u32,
+}
+
+/// Upper bound on `Config::surplus_share_bps`: the user always keeps at least
+/// half of any positive slippage.
+pub const MAX_SURPLUS_SHARE_BPS: u16 = 5_000;
+
+impl Config {
+    pub const LEN: usize = 8 + 32 + 2 + 1 + 1 + 32 + 32 + 2 + 2 + 32 + 2 + 8 + 1 + 1 + 4;
+
+    /// Wallet (or multisig / PDA) `withdraw_fees` pays out to: fees accrue in
+    /// the program-owned vaults and may only be swept to its token accounts.
+    pub fn treasury(&self) -> Pubkey {
+        if self.fee_recipient == Pubkey::default() {
+            self.admin
+        } else {
+            self.fee_recipient
+        }
+    }
+
+    /// Stages `new_admin` for [`Config::accept_admin`]; `Pubkey::default()`
+    /// cancels a pending proposal.
+    pub fn propose_admin(&mut self, signer: &Pubkey, new_admin: Pubkey) -> Result<()> {
+        require_keys_eq!(*signer, self.admin, AggregatorError::Unauthorized);
+        self.pending_admin = new_admin;
+        Ok(())
+    }
+
+    /// Promotes the pending admin, returning the previous one.
+    pub fn accept_admin(&mut self, signer: &Pubkey) -> Result<Pubkey> {
+        require!(
+            self.pending_admin != Pubkey::default(),
+            AggregatorError::NoPendingAdmin
+        );
+        require_keys_eq!(*signer, self.pending_admin, AggregatorError::Unauthorized);
+        let previous = std::mem::replace(&mut self.admin, self.pending_admin);
+        self.pending_admin = Pubkey::default();
+        Ok(previous)
+    }
+
+    pub fn is_dex_paused(&self, dex_id: DexId) -> bool {
+        self.paused_dexes & (1 << dex_id as u8) != 0
+    }
+
+    pub fn set_dex_paused(&mut self, dex_id: DexId, paused: bool) {
+        if paused {
+            self.paused_dexes |= 1 << dex_id as u8;
+        } else {
+            self.paused_dexes &= !(1 << dex_id as u8);
+        }
+    }
+}
+
+/// Fee override for routes into `out_mint`, optionally only from `in_mint`.
+///
+/// Seeded by `[b"fee_tier", in_mint, out_mint]`; an `in_mint` of
+/// `Pubkey::default()` makes the tier apply to every input mint.
+#[account]
+#[derive(Debug)]
+pub struct FeeTier {
+    pub in_mint: Pubkey,
+    pub out_mint: Pubkey,
+    pub fee_bps: u16,
+    pub bump: u8,
+}
+
+impl FeeTier {
+    pub const LEN: usize = 8 + 32 + 32 + 2 + 1;
+
+    /// Whether this tier may price a route from `in_mint` to `out_mint`.
+    pub fn applies_to(&self, in_mint: &Pubkey, out_mint: &Pubkey) -> bool {
+        self.out_mint == *out_mint
+            && (self.in_mint == Pubkey::default() || self.in_mint == *in_mint)
+    }
+}
+
+/// Integrator registered by the admin to receive a share of route fees.
+///
+/// Seeded by `[b"referrer", owner]`; routes paying a referral share must pass
+/// it and pay into a token account owned by `owner`.
+#[account]
+#[derive(Debug)]
+pub struct Referrer {
+    pub owner: Pubkey,
+    pub bump: u8,
+}
+
+impl Referrer {
+    pub const LEN: usize = 8 + 32 + 1;
+}
+
+/// Upper bound on registry entries (one per [`DexId`]).
+pub const MAX_ADAPTERS: usize = 16;
+/// Upper bound on extra account owners an adapter may whitelist.
+pub const MAX_ALLOWED_OWNERS: usize = 6;
+
+/// Admin-managed mapping from [`DexId`] to the deployed AMM program, consulted by
+/// `adapter::dispatch` before any CPI so clusters can be switched (or a DEX
+/// retired) without a redeploy.
+#[account]
+#[derive(Debug)]
+pub struct AdapterRegistry {
+    pub entries: Vec<AdapterEntry>,
+    pub bump: u8,
+}
+
+impl AdapterRegistry {
+    pub const LEN: usize = 8 + 4 + MAX_ADAPTERS * AdapterEntry::LEN + 1;
+
+    pub fn get(&self, dex_id: DexId) -> Option<&AdapterEntry> {
+        self.entries.iter().find(|entry| entry.dex_id == dex_id)
+    }
+
+    /// Inserts `entry`, replacing any existing entry for the same DEX.
+    pub fn upsert(&mut self, entry: AdapterEntry) -> Result<()> {
+        match self.entries.iter_mut().find(|e| e.dex_id == entry.dex_id) {
+            Some(existing) => *existing = entry,
+            None => {
+                require!(
+                    self.entries.len() < MAX_ADAPTERS,
+                    AggregatorError::RegistryFull
+                );
+                self.entries.push(entry);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AdapterEntry {
+    pub dex_id: DexId,
+    /// AMM program the adapter CPIs into on this cluster.
+    pub program_id: Pubkey,
+    /// Disabled adapters reject every leg before any CPI.
+    pub enabled: bool,
+    /// Account owners accepted in addition to `program_id` and the token programs.
+    pub allowed_owners: Vec<Pubkey>,
+}
+
+impl AdapterEntry {
+    pub const LEN: usize = 1 + 32 + 1 + 4 + MAX_ALLOWED_OWNERS * 32;
+
+    /// Whether an account owned by `owner` may be forwarded to this adapter's CPI.
+    pub fn allows_owner(&self, owner: &Pubkey) -> bool {
+        *owner == self.program_id || is_token_program(owner) || self.allowed_owners.contains(owner)
+    }
 }
@@ -1,25 +1,85 @@
 //! Unit & property tests for the on-chain aggregator program.
 //!
 //! The goals of this test-suite are:
-//! 1.  Verify that every adapter returns the expected `(in, out, consumed)` triple for
+//! 1.  Verify that every adapter returns the expected `LegFill` (spent, received, consumed) for
 //!     simple dummy `SwapLeg`s.
 //! 2.  Verify that the adapter correctly errors when the caller provides an
 //!     insufficient slice of `remaining_accounts`.
 //! 3.  Fuzz-test (via `proptest`) that the `accounts_consumed` value reported by
 //!     the adapter always matches the `account_count` field embedded in `SwapLeg`.
 //!
-//! The real CPI calls are compiled out in tests (`#[cfg(not(test))]` blocks inside
-//! the adapters) so the tests run completely off-chain and do **not** spin up a
-//! local validator. This keeps the feedback loop fast while still covering the
+//! Off-chain builds turn `program::invoke` into a no-op, so legs run through the
+//! full encode / validate path without spinning up a local validator.  What the
+//! router would send is asserted on the `Instruction` returned by
+//! `adapter::prepare`. This keeps the feedback loop fast while still covering the
 //! routing logic.
 
 #![cfg(test)]
 
 use super::*;
-use crate::{adapter, DexId, SwapLeg};
+use crate::state::{AdapterEntry, AdapterRegistry, Config};
+use crate::{adapter, DexId, SwapLeg, SwapParams};
 // Keep imports minimal for unit tests
 // Removed property-based tests to keep the suite lean and deterministic
 
+/// Exact-input params for `dex` spending `amount`.
+fn dummy_params(dex: DexId, amount: u64, min_out: u64) -> SwapParams {
+    match dex {
+        DexId::LifinityV2 => SwapParams::LifinityV2 {
+            amount_in: amount,
+            minimum_amount_out: min_out,
+        },
+        DexId::OrcaWhirlpool => SwapParams::OrcaWhirlpool {
+            amount,
+            other_amount_threshold: min_out,
+            sqrt_price_limit: 0,
+            amount_specified_is_input: true,
+            a_to_b: true,
+            v2: false,
+        },
+        DexId::SolarCp => SwapParams::SolarCp {
+            amount,
+            other_amount_threshold: min_out,
+            base_input: true,
+        },
+        DexId::SolarClmm => SwapParams::SolarClmm {
+            amount,
+            other_amount_threshold: min_out,
+            sqrt_price_limit_x64: 0,
+            is_base_input: true,
+            v2: false,
+        },
+        DexId::Invariant => SwapParams::Invariant {
+            x_to_y: true,
+            amount,
+            by_amount_in: true,
+            sqrt_price_limit: 0,
+        },
+        DexId::RaydiumAmmV4 => SwapParams::RaydiumAmmV4 {
+            amount,
+            other_amount_threshold: min_out,
+            base_input: true,
+            v2: false,
+        },
+        DexId::RaydiumCpmm => SwapParams::RaydiumCpmm {
+            amount,
+            other_amount_threshold: min_out,
+            base_input: true,
+        },
+        DexId::RaydiumClmm => SwapParams::RaydiumClmm {
+            amount,
+            other_amount_threshold: min_out,
+            sqrt_price_limit_x64: 0,
+            is_base_input: true,
+        },
+        DexId::MeteoraDlmm => SwapParams::MeteoraDlmm {
+            amount,
+            other_amount_threshold: min_out,
+            base_input: true,
+        },
+    }
+}
+
 /// Helper to build a minimal `SwapLeg` for a given DEX.
 fn dummy_leg(dex: DexId, in_amount: u64, min_out: u64, account_count: u8) -> SwapLeg {
     SwapLeg {
@@ -27,12 +87,87 @@ fn dummy_leg(dex: DexId, in_amount: u64, min_out: u64, account_count: u8) -> Swa
         in_amount,
         min_out,
         account_count,
-        data: vec![],
+        params: dummy_params(dex, in_amount, min_out),
         in_mint: Pubkey::default(),
         out_mint: Pubkey::default(),
     }
 }
 
+/// Registry with every adapter registered at its default program ID and enabled.
+fn test_registry() -> AdapterRegistry {
+    AdapterRegistry {
+        entries: ALL_DEXES
+            .iter()
+            .map(|dex| AdapterEntry {
+                enabled: true,
+                ..adapter::default_entry(*dex)
+            })
+            .collect(),
+        bump: 255,
+    }
+}
+
+/// Unpaused config with a 0.3 % fee.
+fn test_config() -> Config {
+    Config {
+        admin: Pubkey::new_unique(),
+        fee_bps: 30,
+        paused: false,
+        bump: 255,
+        pending_admin: Pubkey::default(),
+        fee_recipient: Pubkey::default(),
+        max_referral_bps: 0,
+        surplus_share_bps: 0,
+        oracle_program: Pubkey::default(),
+        max_price_deviation_bps: 0,
+        max_oracle_staleness_slots: 0,
+        fee_side: FeeSide::Output,
+        require_oracle: false,
+        paused_dexes: 0,
+    }
+}
+
+/// Account backed by leaked buffers, so it can stand in for any `'info` account.
+fn static_account(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> AccountInfo<'static> {
+    AccountInfo::new(
+        Box::leak(Box::new(key)),
+        false,
+        true,
+        Box::leak(Box::new(0u64)),
+        Box::leak(data.into_boxed_slice()),
+        Box::leak(Box::new(owner)),
+        false,
+        0,
+    )
+}
+
+/// Data-less accounts given as `(key, owner, executable)`.
+fn layout_accounts(layout: &[(Pubkey, Pubkey, bool)]) -> Vec<AccountInfo<'static>> {
+    layout
+        .iter()
+        .map(|&(key, owner, executable)| AccountInfo {
+            executable,
+            ..static_account(key, owner, vec![])
+        })
+        .collect()
+}
+
+/// Initialised SPL token account of `mint` owned by `owner`.
+fn token_account_data(mint: Pubkey, owner: Pubkey, amount: u64) -> Vec<u8> {
+    use anchor_lang::solana_program::program_pack::Pack;
+    use anchor_spl::token::spl_token::state::{Account, AccountState};
+    let mut data = vec![0u8; Account::LEN];
+    Account {
+        mint,
+        owner,
+        amount,
+        state: AccountState::Initialized,
+        ..Default::default()
+    }
+    .pack_into_slice(&mut data);
+    data
+}
+
 /// List of all supported DEX IDs used throughout the tests.
 const ALL_DEXES: &[DexId] = &[
     DexId::LifinityV2,
@@ -40,19 +175,28 @@ const ALL_DEXES: &[DexId] = &[
     DexId::SolarCp,
     DexId::SolarClmm,
     DexId::Invariant,
+    DexId::RaydiumAmmV4,
+    DexId::RaydiumCpmm,
+    DexId::RaydiumClmm,
+    DexId::MeteoraDlmm,
 ];
 
 // ------------- Basic happy-path tests ------------- //
 
 #[test]
-fn adapter_happy_path_returns_expected_triplet() {
+fn adapter_happy_path_returns_expected_fill() {
     for &dex in ALL_DEXES {
         let leg = dummy_leg(dex, 1_000, 950, 0); // `account_count = 0` ⇒ no remaining_accounts needed
-        let (spent, received, consumed) =
-            adapter::dispatch(&leg, &[]).expect("adapter call failed");
-        assert_eq!(spent, 1_000, "spent_in mismatch for {:?}", dex);
-        assert_eq!(received, 950, "received_out mismatch for {:?}", dex);
-        assert_eq!(consumed, 0, "accounts_consumed mismatch for {:?}", dex);
+        let fill = adapter::dispatch(&leg, &[], &test_registry(), &test_config())
+            .expect("adapter call failed");
+        // No accounts ⇒ no CPI ⇒ nothing moved; the hints are never echoed back.
+        assert_eq!(fill.spent, 0, "spent_in mismatch for {:?}", dex);
+        assert_eq!(fill.received, 0, "received_out mismatch for {:?}", dex);
+        assert_eq!(fill.consumed, 0, "accounts_consumed mismatch for {:?}", dex);
+        assert_eq!(
+            (fill.input, fill.output),
+            (Pubkey::default(), Pubkey::default())
+        );
     }
 }
 
@@ -60,7 +204,14 @@ fn adapter_happy_path_returns_expected_triplet() {
 fn adapter_errors_on_insufficient_remaining_accounts() {
     // Provide a leg that claims it needs 2 accounts but pass in only 1.
     let leg = dummy_leg(DexId::LifinityV2, 100, 90, 2);
-    let err = adapter::lifinity::invoke(&leg, &[]).unwrap_err();
+    let entry = adapter::default_entry(DexId::LifinityV2);
+    let err = adapter::invoke::<adapter::lifinity::Lifinity>(
+        &leg,
+        adapter::encode(&leg).unwrap(),
+        &[],
+        &entry,
+    )
+    .unwrap_err();
     // The error should map to our `RemainingAccountsMismatch` variant.
     match err {
         anchor_lang::error::Error::AnchorError(anchor_err) => {
@@ -75,13 +226,8 @@ fn adapter_errors_on_insufficient_accounts_for_all_dexes() {
     // For every DEX, require that providing fewer accounts than declared results in an error.
     for &dex in ALL_DEXES {
         let leg = dummy_leg(dex, 123, 100, 2);
-        let result = match dex {
-            DexId::LifinityV2 => crate::adapter::lifinity::invoke(&leg, &[]),
-            DexId::OrcaWhirlpool => crate::adapter::orca::invoke(&leg, &[]),
-            DexId::SolarCp => crate::adapter::solar_cp::invoke(&leg, &[]),
-            DexId::SolarClmm => crate::adapter::solar_clmm::invoke(&leg, &[]),
-            DexId::Invariant => crate::adapter::invariant::invoke(&leg, &[]),
-        };
+        let entry = adapter::default_entry(dex);
+        let result = adapter::invoke_leg(&leg, adapter::encode(&leg).unwrap(), &[], &entry);
 
         assert!(
             result.is_err(),
@@ -92,6 +238,43 @@ fn adapter_errors_on_insufficient_accounts_for_all_dexes() {
 }
 
 #[test]
+fn prepare_builds_the_validated_cpi() {
+    use adapter::{lifinity::Lifinity, SWAP_DISCRIMINATOR};
+    let token = anchor_spl::token::ID;
+    let layout: Vec<(Pubkey, Pubkey, bool)> = (0..5)
+        .map(|_| (Pubkey::new_unique(), token, false))
+        .collect();
+    let leg = dummy_leg(DexId::LifinityV2, 100, 90, 5);
+    let entry = adapter::default_entry(DexId::LifinityV2);
+
+    let accounts = layout_accounts(&layout);
+    let ix = adapter::prepare::<Lifinity>(adapter::encode(&leg).unwrap(), &accounts, &entry)
+        .expect("valid leg rejected");
+    assert_eq!(ix.program_id, entry.program_id);
+    assert_eq!(ix.data[..8], SWAP_DISCRIMINATOR);
+    assert_eq!(
+        ix.data[8..],
+        [100u64.to_le_bytes(), 90u64.to_le_bytes()].concat()
+    );
+    let keys: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+    let expected: Vec<Pubkey> = layout.iter().map(|(key, _, _)| *key).collect();
+    assert_eq!(keys, expected);
+    assert!(ix
+        .accounts
+        .iter()
+        .all(|meta| meta.is_writable && !meta.is_signer));
+
+    // The owner whitelist runs before any CPI is built, in tests too.
+    let mut bad = layout.clone();
+    bad[2].1 = Pubkey::new_unique();
+    let accounts = layout_accounts(&bad);
+    let err = adapter::invoke::<Lifinity>(&leg, adapter::encode(&leg).unwrap(), &accounts, &entry)
+        .unwrap_err();
+    assert_eq!(err, AggregatorError::InvalidProgramId.into());
+}
+
+#[test]
+#[allow(clippy::assertions_on_constants)]
 fn max_legs_constant_is_reasonable() {
     // Guard against accidental bumping that might blow compute.
     assert!(crate::MAX_LEGS <= 16, "MAX_LEGS unexpectedly high");
@@ -112,6 +295,111 @@ fn fee_calculation_never_overflows_and_is_bounded() {
     }
 }
 
+#[test]
+fn gross_up_covers_fee_for_requested_net() {
+    for net in [0u64, 1, 999, 1_000_000, u64::MAX / 20_000] {
+        for fee_bps in [0u16, 1, 30, 2_500, 9_999] {
+            let gross = crate::router::gross_up_for_fee(net, fee_bps).unwrap();
+            let fee = crate::router::compute_fee(gross, fee_bps).unwrap();
+            assert!(gross - fee >= net, "gross-up too small");
+            // One token less must no longer be enough (minimality).
+            if gross > 0 && net > 0 {
+                let fee_below = crate::router::compute_fee(gross - 1, fee_bps).unwrap();
+                assert!(gross - 1 - fee_below < net, "gross-up not minimal");
+            }
+        }
+    }
+}
+
+#[test]
+fn referral_share_splits_fee_without_dust() {
+    for out_amount in [1u64, 333, 1_000_000, u64::MAX / 2] {
+        for referral_bps in [0u16, 1, 2_500, 10_000] {
+            let fee_amount = crate::router::compute_fee(out_amount, 30).unwrap();
+            let referral_fee = crate::router::compute_fee(fee_amount, referral_bps).unwrap();
+            let settlement = crate::router::Settlement {
+                in_mint: Pubkey::default(),
+                delta_spent: 0,
+                delta_out: out_amount,
+                gross_out: out_amount,
+                fee_amount,
+                fee_side: FeeSide::Output,
+                referral_fee,
+                surplus_fee: 0,
+                fee_bps: 30,
+                transfer_fee_withheld: 0,
+                legs: 1,
+            };
+            let protocol_fee = settlement.protocol_fee().unwrap();
+            assert_eq!(protocol_fee + referral_fee, fee_amount);
+            assert_eq!(settlement.user_receive().unwrap(), out_amount - fee_amount);
+        }
+    }
+}
+
+#[test]
+fn referral_share_requires_a_registered_referrer_other_than_the_user() {
+    use crate::router::check_referrer;
+    let user = Pubkey::new_unique();
+    let integrator = Pubkey::new_unique();
+    let registered = crate::state::Referrer {
+        owner: integrator,
+        bump: 255,
+    };
+
+    check_referrer(Some(&registered), &integrator, &user).unwrap();
+
+    let err = check_referrer(None, &integrator, &user).unwrap_err();
+    assert_eq!(err, AggregatorError::UnregisteredReferrer.into());
+    // A registration does not cover token accounts of another wallet.
+    let err = check_referrer(Some(&registered), &user, &user).unwrap_err();
+    assert_eq!(err, AggregatorError::UnregisteredReferrer.into());
+
+    // Even a registered referrer cannot route and rebate itself.
+    let err = check_referrer(Some(&registered), &integrator, &integrator).unwrap_err();
+    assert_eq!(err, AggregatorError::SelfReferral.into());
+}
+
+#[test]
+fn gross_up_rejects_full_fee() {
+    assert!(crate::router::gross_up_for_fee(1, 10_000).is_err());
+}
+
+#[test]
+fn zero_min_out_routes_at_a_full_fee() {
+    use crate::router::{gross_up_for_fee, required_gross_out};
+    // Nothing to gross up: a route without a minimum output must not trip the
+    // fee-rate check, even at 100%.
+    assert_eq!(gross_up_for_fee(0, 10_000).unwrap(), 0);
+    assert_eq!(required_gross_out(0, FeeSide::Output, 10_000).unwrap(), 0);
+}
+
+// ------------- Exact-out planning ------------- //
+
+#[test]
+fn exact_out_plan_accepts_covering_chain() {
+    // leg 0 must deliver what leg 1 consumes, leg 1 must deliver the requested output.
+    let legs = vec![
+        dummy_leg(DexId::OrcaWhirlpool, 500, 1_000, 0),
+        dummy_leg(DexId::Invariant, 1_000, 2_000, 0),
+    ];
+    crate::router::plan_exact_out(&legs, 2_000).expect("plan should be accepted");
+}
+
+#[test]
+fn exact_out_plan_rejects_gaps() {
+    // Final leg promises less than requested.
+    let legs = vec![dummy_leg(DexId::OrcaWhirlpool, 500, 1_999, 0)];
+    assert!(crate::router::plan_exact_out(&legs, 2_000).is_err());
+
+    // Intermediate leg delivers less than the next leg consumes.
+    let legs = vec![
+        dummy_leg(DexId::OrcaWhirlpool, 500, 999, 0),
+        dummy_leg(DexId::Invariant, 1_000, 2_000, 0),
+    ];
+    assert!(crate::router::plan_exact_out(&legs, 2_000).is_err());
+}
+
 // ------------- Compiler guard ------------- //
 // If new DEXes are added but the test suite is not updated, fail loudly.
 #[test]
@@ -119,7 +407,7 @@ fn exhaustive_dex_enum_coverage() {
     use std::collections::HashSet;
     let from_array: HashSet<u8> = ALL_DEXES.iter().map(|d| *d as u8).collect();
     let from_enum: HashSet<u8> = (0u8..=u8::MAX)
-        .filter(|v| matches!(*v, 0..=4)) // Current enum variants occupy 0-4.
+        .filter(|v| matches!(*v, 0..=8)) // Current enum variants occupy 0-8.
         .collect();
 
     assert_eq!(
@@ -127,3 +415,1757 @@ fn exhaustive_dex_enum_coverage() {
         "`ALL_DEXES` constant missing a variant – please update the test suite."
     );
 }
+
+// ------------- Split-route shape ------------- //
+
+fn mint_leg(dex: DexId, in_mint: Pubkey, out_mint: Pubkey) -> SwapLeg {
+    SwapLeg {
+        in_mint,
+        out_mint,
+        ..dummy_leg(dex, 0, 0, 0)
+    }
+}
+
+#[test]
+fn split_route_accepts_converging_branches() {
+    let (src, mid, dst) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    let direct = vec![mint_leg(DexId::OrcaWhirlpool, src, dst)];
+    let two_hop = vec![
+        mint_leg(DexId::Invariant, src, mid),
+        mint_leg(DexId::LifinityV2, mid, dst),
+    ];
+    let total = crate::router::validate_branches(&[&direct, &two_hop], src, dst)
+        .expect("split route should be accepted");
+    assert_eq!(total, 3);
+}
+
+#[test]
+fn split_route_rejects_bad_branches() {
+    let (src, mid, dst) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    let good = vec![mint_leg(DexId::OrcaWhirlpool, src, dst)];
+
+    // Branch stops at an intermediate mint.
+    let dangling = vec![mint_leg(DexId::Invariant, src, mid)];
+    assert!(crate::router::validate_branches(&[&good, &dangling], src, dst).is_err());
+
+    // Branch breaks mint continuity.
+    let broken = vec![
+        mint_leg(DexId::Invariant, src, mid),
+        mint_leg(DexId::LifinityV2, src, dst),
+    ];
+    assert!(crate::router::validate_branches(&[&good, &broken], src, dst).is_err());
+
+    // Empty branch and empty route.
+    let empty: Vec<SwapLeg> = vec![];
+    assert!(crate::router::validate_branches(&[&good, &empty], src, dst).is_err());
+    assert!(crate::router::validate_branches(&[], src, dst).is_err());
+
+    // Too many branches.
+    let branches: Vec<&[SwapLeg]> = (0..=crate::MAX_BRANCHES).map(|_| good.as_slice()).collect();
+    assert!(crate::router::validate_branches(&branches, src, dst).is_err());
+}
+
+// ------------- Amount chaining ------------- //
+
+#[test]
+fn set_in_amount_rewrites_encoded_amount() {
+    for &dex in ALL_DEXES {
+        let mut leg = dummy_leg(dex, 1, 7, 0);
+        let before = adapter::encode(&leg).unwrap();
+        adapter::set_in_amount(&mut leg, 0x0102_0304_0506_0708).expect("rewrite failed");
+        let after = adapter::encode(&leg).unwrap();
+
+        // Invariant encodes `x_to_y` ahead of the amount; AMM v4 has a one-byte tag.
+        let offset = match dex {
+            DexId::Invariant => 9,
+            DexId::RaydiumAmmV4 => 1,
+            _ => 8,
+        };
+        assert_eq!(
+            after[offset..offset + 8],
+            0x0102_0304_0506_0708u64.to_le_bytes(),
+            "amount not rewritten for {:?}",
+            dex
+        );
+        // Bytes around the amount are untouched.
+        assert_eq!(after[..offset], before[..offset]);
+        assert_eq!(after[offset + 8..], before[offset + 8..]);
+        assert_eq!(leg.in_amount, 0x0102_0304_0506_0708);
+        adapter::check_params(&leg).expect("rewritten leg inconsistent");
+    }
+}
+
+#[test]
+fn set_in_amount_rejects_exact_out_params() {
+    let mut leg = dummy_leg(DexId::SolarCp, 100, 50, 0);
+    leg.params = SwapParams::SolarCp {
+        amount: 50,
+        other_amount_threshold: 100,
+        base_input: false,
+    };
+    assert!(adapter::set_in_amount(&mut leg, 5).is_err());
+}
+
+// ------------- Typed swap params ------------- //
+
+#[test]
+fn swap_params_encode_anchor_instructions() {
+    use adapter::{orca, solar_clmm, solar_cp, SWAP_DISCRIMINATOR};
+    let encode = |dex, params| {
+        let mut leg = dummy_leg(dex, 0, 0, 0);
+        leg.params = params;
+        adapter::encode(&leg).unwrap()
+    };
+
+    let data = encode(DexId::LifinityV2, dummy_params(DexId::LifinityV2, 5, 3));
+    assert_eq!(data[..8], SWAP_DISCRIMINATOR);
+    assert_eq!(data[8..], [5u64.to_le_bytes(), 3u64.to_le_bytes()].concat());
+
+    // `swap_base_output(max_amount_in, amount_out)` puts the threshold first.
+    let data = encode(
+        DexId::SolarCp,
+        SwapParams::SolarCp {
+            amount: 5,
+            other_amount_threshold: 9,
+            base_input: false,
+        },
+    );
+    assert_eq!(data[..8], solar_cp::SWAP_BASE_OUTPUT_DISCRIMINATOR);
+    assert_eq!(data[8..], [9u64.to_le_bytes(), 5u64.to_le_bytes()].concat());
+
+    let data = encode(
+        DexId::SolarClmm,
+        SwapParams::SolarClmm {
+            amount: 5,
+            other_amount_threshold: 3,
+            sqrt_price_limit_x64: 1 << 64,
+            is_base_input: true,
+            v2: true,
+        },
+    );
+    assert_eq!(data[..8], solar_clmm::SWAP_V2_DISCRIMINATOR);
+    assert_eq!(data[24..40], (1u128 << 64).to_le_bytes());
+    assert_eq!(data.len(), 8 + 8 + 8 + 16 + 1);
+
+    // Whirlpool `swap`: a_to_b sits at byte 41; `swap_v2` adds a `None`.
+    let whirlpool = |v2| SwapParams::OrcaWhirlpool {
+        amount: 5,
+        other_amount_threshold: 3,
+        sqrt_price_limit: 0,
+        amount_specified_is_input: true,
+        a_to_b: true,
+        v2,
+    };
+    let data = encode(DexId::OrcaWhirlpool, whirlpool(false));
+    assert_eq!(data.len(), 42);
+    assert_eq!(data[41], 1);
+    let data = encode(DexId::OrcaWhirlpool, whirlpool(true));
+    assert_eq!(data[..8], orca::SWAP_V2_DISCRIMINATOR);
+    assert_eq!(data[42..], [0]);
+
+    let data = encode(
+        DexId::OrcaWhirlpool,
+        SwapParams::OrcaWhirlpoolTwoHop {
+            amount: 5,
+            other_amount_threshold: 3,
+            amount_specified_is_input: true,
+            a_to_b_one: false,
+            a_to_b_two: true,
+            sqrt_price_limit_one: 0,
+            sqrt_price_limit_two: 0,
+            v2: false,
+        },
+    );
+    assert_eq!(data[..8], orca::TWO_HOP_SWAP_DISCRIMINATOR);
+    assert_eq!(data[24..27], [1, 0, 1]);
+    assert_eq!(data.len(), 8 + 8 + 8 + 3 + 16 + 16);
+}
+
+#[test]
+fn swap_params_must_agree_with_leg() {
+    // Exact-input: the params spend exactly `in_amount`.
+    let mut leg = dummy_leg(DexId::OrcaWhirlpool, 100, 90, 0);
+    adapter::check_params(&leg).expect("consistent leg rejected");
+    leg.in_amount = 99;
+    assert_eq!(
+        adapter::check_params(&leg).unwrap_err(),
+        AggregatorError::SwapParamsMismatch.into()
+    );
+
+    // Params of another DEX.
+    let mut leg = dummy_leg(DexId::OrcaWhirlpool, 100, 90, 0);
+    leg.params = dummy_params(DexId::Invariant, 100, 90);
+    assert!(adapter::check_params(&leg).is_err());
+    assert!(adapter::encode(&leg).is_err());
+    assert!(adapter::dispatch(&leg, &[], &test_registry(), &test_config()).is_err());
+
+    // Exact-output: target at least `min_out`, spend at most `in_amount`.
+    let exact_out = |amount, max_in| SwapParams::SolarCp {
+        amount,
+        other_amount_threshold: max_in,
+        base_input: false,
+    };
+    let mut leg = dummy_leg(DexId::SolarCp, 100, 90, 0);
+    leg.params = exact_out(90, 100);
+    adapter::check_params(&leg).expect("exact-out leg rejected");
+    leg.params = exact_out(89, 100);
+    assert!(adapter::check_params(&leg).is_err());
+    leg.params = exact_out(90, 101);
+    assert!(adapter::check_params(&leg).is_err());
+
+    // Invariant takes no threshold, so only the output target is checked.
+    leg = dummy_leg(DexId::Invariant, 100, 90, 0);
+    leg.params = SwapParams::Invariant {
+        x_to_y: false,
+        amount: 90,
+        by_amount_in: false,
+        sqrt_price_limit: 0,
+    };
+    adapter::check_params(&leg).expect("exact-out Invariant leg rejected");
+}
+
+#[test]
+fn orca_token_accounts_follow_direction_and_layout() {
+    use adapter::{orca::OrcaWhirlpool, DexAdapter};
+    // Legacy `swap` layout.
+    let mut data = vec![0u8; 42];
+    data[..8].copy_from_slice(&adapter::SWAP_DISCRIMINATOR);
+    data[41] = 1; // a_to_b
+    assert_eq!(OrcaWhirlpool::token_accounts(&data).unwrap(), (3, 5));
+    data[41] = 0;
+    assert_eq!(OrcaWhirlpool::token_accounts(&data).unwrap(), (5, 3));
+
+    data[..8].copy_from_slice(&adapter::orca::SWAP_V2_DISCRIMINATOR);
+    data[41] = 1;
+    assert_eq!(OrcaWhirlpool::token_accounts(&data).unwrap(), (7, 9));
+
+    // `two_hop_swap`: input from hop one, output from hop two.
+    data[..8].copy_from_slice(&adapter::orca::TWO_HOP_SWAP_DISCRIMINATOR);
+    data[25] = 1; // a_to_b_one
+    data[26] = 0; // a_to_b_two
+    assert_eq!(OrcaWhirlpool::token_accounts(&data).unwrap(), (4, 8));
+    data[25] = 0;
+    data[26] = 1;
+    assert_eq!(OrcaWhirlpool::token_accounts(&data).unwrap(), (6, 10));
+
+    data[..8].copy_from_slice(&adapter::orca::TWO_HOP_SWAP_V2_DISCRIMINATOR);
+    assert_eq!(OrcaWhirlpool::token_accounts(&data).unwrap(), (8, 13));
+
+    data[..8].copy_from_slice(&[0; 8]);
+    assert!(OrcaWhirlpool::token_accounts(&data).is_err());
+}
+
+#[test]
+fn adapters_only_forward_whitelisted_swap_instructions() {
+    use adapter::{
+        invariant::Invariant, lifinity::Lifinity, orca, orca::OrcaWhirlpool, solar_clmm::SolarClmm,
+        solar_cp, solar_cp::SolarCp, DexAdapter, SWAP_DISCRIMINATOR,
+    };
+    let with = |discriminator: [u8; 8]| {
+        let mut data = vec![0u8; 42];
+        data[..8].copy_from_slice(&discriminator);
+        data
+    };
+
+    assert!(Lifinity::check_instruction(&with(SWAP_DISCRIMINATOR)).is_ok());
+    assert!(Invariant::check_instruction(&with(SWAP_DISCRIMINATOR)).is_ok());
+    assert!(SolarClmm::check_instruction(&with(orca::SWAP_V2_DISCRIMINATOR)).is_ok());
+    assert!(SolarCp::check_instruction(&with(solar_cp::SWAP_BASE_INPUT_DISCRIMINATOR)).is_ok());
+    assert!(SolarCp::check_instruction(&with(solar_cp::SWAP_BASE_OUTPUT_DISCRIMINATOR)).is_ok());
+    for discriminator in [
+        SWAP_DISCRIMINATOR,
+        orca::SWAP_V2_DISCRIMINATOR,
+        orca::TWO_HOP_SWAP_DISCRIMINATOR,
+        orca::TWO_HOP_SWAP_V2_DISCRIMINATOR,
+    ] {
+        assert!(OrcaWhirlpool::check_instruction(&with(discriminator)).is_ok());
+    }
+
+    // Whirlpool `close_position`, a non-swap instruction, and truncated data.
+    let close_position = [123, 134, 81, 0, 49, 68, 98, 98];
+    assert_eq!(
+        OrcaWhirlpool::check_instruction(&with(close_position)).unwrap_err(),
+        AggregatorError::DisallowedInstruction.into()
+    );
+    assert!(SolarCp::check_instruction(&with(SWAP_DISCRIMINATOR)).is_err());
+    assert!(Lifinity::check_instruction(&with(orca::SWAP_V2_DISCRIMINATOR)).is_err());
+    assert!(Invariant::check_instruction(&SWAP_DISCRIMINATOR[..4]).is_err());
+}
+
+// ------------- Per-leg balance deltas ------------- //
+
+#[test]
+fn token_account_state_reads_mint_and_amount() {
+    let (key, mint, user) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    let ai = static_account(
+        key,
+        anchor_spl::token::ID,
+        token_account_data(mint, user, 42),
+    );
+    assert_eq!(adapter::token_account_state(&ai).unwrap(), (mint, 42));
+
+    // Accounts not owned by a token program are rejected.
+    let fake = static_account(key, key, token_account_data(mint, user, 42));
+    assert!(adapter::token_account_state(&fake).is_err());
+}
+
+#[test]
+fn dispatch_rejects_leg_accounts_with_wrong_mint() {
+    let (in_mint, out_mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let user = Pubkey::new_unique();
+    // Lifinity places source/destination at 3/4; the destination holds the wrong mint.
+    let accounts: Vec<AccountInfo> = (0..5)
+        .map(|i| {
+            let amount = if i == 3 { 100 } else { 0 };
+            static_account(
+                Pubkey::new_unique(),
+                anchor_spl::token::ID,
+                token_account_data(in_mint, user, amount),
+            )
+        })
+        .collect();
+
+    let mut leg = dummy_leg(DexId::LifinityV2, 100, 0, 5);
+    leg.in_mint = in_mint;
+    leg.out_mint = out_mint;
+    assert!(adapter::dispatch(&leg, &accounts, &test_registry(), &test_config()).is_err());
+
+    // With matching mints the leg passes validation; the off-chain CPI moves nothing.
+    leg.out_mint = in_mint;
+    assert_eq!(
+        adapter::dispatch(&leg, &accounts, &test_registry(), &test_config()).unwrap(),
+        adapter::LegFill {
+            input: accounts[3].key(),
+            output: accounts[4].key(),
+            spent: 0,
+            received: 0,
+            consumed: 5,
+        }
+    );
+}
+
+/// Fill of a leg swapping from token account `input` into `output`.
+fn fill(input: Pubkey, output: Pubkey) -> adapter::LegFill {
+    adapter::LegFill {
+        input,
+        output,
+        spent: 100,
+        received: 90,
+        consumed: 5,
+    }
+}
+
+#[test]
+fn branch_chains_user_source_to_user_destination() {
+    use crate::router::check_branch_accounts;
+    let (source, middle, destination) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    check_branch_accounts(&[fill(source, destination)], 0, &source, &destination).unwrap();
+    check_branch_accounts(
+        &[fill(source, middle), fill(middle, destination)],
+        0,
+        &source,
+        &destination,
+    )
+    .unwrap();
+}
+
+#[test]
+fn first_leg_must_spend_from_user_source() {
+    let (source, other, destination) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    // Another account of the user with the source mint.
+    let err =
+        crate::router::check_branch_accounts(&[fill(other, destination)], 0, &source, &destination)
+            .unwrap_err();
+    assert_eq!(err, AggregatorError::LegInputMismatch.into());
+}
+
+#[test]
+fn later_legs_must_spend_the_previous_leg_output() {
+    let (source, middle, other, destination) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    let err = crate::router::check_branch_accounts(
+        &[fill(source, middle), fill(other, destination)],
+        0,
+        &source,
+        &destination,
+    )
+    .unwrap_err();
+    assert_eq!(err, AggregatorError::LegInputMismatch.into());
+}
+
+#[test]
+fn last_leg_must_deliver_to_user_destination() {
+    let (source, middle, other, destination) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    let err = crate::router::check_branch_accounts(
+        &[fill(source, middle), fill(middle, other)],
+        0,
+        &source,
+        &destination,
+    )
+    .unwrap_err();
+    assert_eq!(err, AggregatorError::LegOutputMismatch.into());
+
+    // A leg without accounts moves nothing and links to nothing.
+    let empty = fill(Pubkey::default(), Pubkey::default());
+    assert!(crate::router::check_branch_accounts(&[empty], 0, &source, &destination).is_err());
+}
+
+#[test]
+fn token_2022_accounts_are_supported() {
+    assert!(adapter::is_token_program(&anchor_spl::token::ID));
+    assert!(adapter::is_token_program(&anchor_spl::token_2022::ID));
+    assert!(!adapter::is_token_program(&Pubkey::new_unique()));
+
+    // Token-2022 accounts with extensions are longer than the base layout.
+    let mint = Pubkey::new_unique();
+    let mut data = token_account_data(mint, Pubkey::new_unique(), 7);
+    data.resize(165 + 1 + 12, 0);
+    let ai = static_account(Pubkey::new_unique(), anchor_spl::token_2022::ID, data);
+    assert_eq!(adapter::token_account_state(&ai).unwrap(), (mint, 7));
+}
+
+// ------------- Token-2022 transfer fees ------------- //
+
+/// Builds Token-2022 mint data carrying a `TransferFeeConfig` extension.
+fn transfer_fee_mint_data(fee_bps: u16, maximum_fee: u64) -> Vec<u8> {
+    use anchor_spl::token_2022::spl_token_2022::{
+        extension::{
+            transfer_fee::{TransferFee, TransferFeeConfig},
+            BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+        },
+        state::Mint,
+    };
+
+    let len = ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::TransferFeeConfig])
+        .unwrap();
+    let mut data = vec![0u8; len];
+    let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+    let fee = TransferFee {
+        epoch: 0.into(),
+        maximum_fee: maximum_fee.into(),
+        transfer_fee_basis_points: fee_bps.into(),
+    };
+    let extension = state.init_extension::<TransferFeeConfig>(true).unwrap();
+    extension.older_transfer_fee = fee;
+    extension.newer_transfer_fee = fee;
+    state.base = Mint {
+        decimals: 6,
+        is_initialized: true,
+        ..Default::default()
+    };
+    state.pack_base();
+    state.init_account_type().unwrap();
+    data
+}
+
+#[test]
+fn transfer_fee_config_is_read_from_token_2022_mints() {
+    let key = Pubkey::new_unique();
+    let mint = static_account(
+        key,
+        anchor_spl::token_2022::ID,
+        transfer_fee_mint_data(150, u64::MAX),
+    );
+    let config = crate::transfer_fee::config(&mint)
+        .unwrap()
+        .expect("extension missing");
+    assert_eq!(
+        crate::transfer_fee::fee_on(Some(&config), 0, 10_000).unwrap(),
+        150
+    );
+
+    // Classic SPL mints never carry transfer fees.
+    let classic = static_account(key, anchor_spl::token::ID, vec![0u8; 82]);
+    assert!(crate::transfer_fee::config(&classic).unwrap().is_none());
+    assert_eq!(crate::transfer_fee::fee_on(None, 0, 10_000).unwrap(), 0);
+}
+
+#[test]
+fn withheld_fee_reconstructs_gross_amount() {
+    for (fee_bps, maximum_fee) in [(1u16, u64::MAX), (150, u64::MAX), (500, 1_000)] {
+        let mint = static_account(
+            Pubkey::new_unique(),
+            anchor_spl::token_2022::ID,
+            transfer_fee_mint_data(fee_bps, maximum_fee),
+        );
+        let config = crate::transfer_fee::config(&mint).unwrap();
+        for gross in [1u64, 99, 10_000, 1_234_567, 1_000_000_000] {
+            let fee = crate::transfer_fee::fee_on(config.as_ref(), 0, gross).unwrap();
+            let net = gross - fee;
+            if net == 0 {
+                // Fully withheld dust transfers are invisible in the balance delta.
+                continue;
+            }
+            let withheld = crate::transfer_fee::fee_withheld_from(config.as_ref(), 0, net).unwrap();
+            // The inverse may round up by at most one token, never down.
+            assert!(
+                withheld >= fee && withheld <= fee + 1,
+                "bad inverse for gross {gross}"
+            );
+        }
+    }
+}
+
+#[test]
+fn split_route_withheld_fee_is_reversed_per_branch() {
+    // 5% capped at 1_000 per transfer.
+    let mint = static_account(
+        Pubkey::new_unique(),
+        anchor_spl::token_2022::ID,
+        transfer_fee_mint_data(500, 1_000),
+    );
+    let config = crate::transfer_fee::config(&mint).unwrap();
+
+    // Two branches each sending 20_000 gross hit the cap separately.
+    let branches = [19_000u64, 19_000];
+    let per_branch =
+        crate::transfer_fee::fee_withheld_from_each(config.as_ref(), 0, &branches).unwrap();
+    assert_eq!(per_branch, 2_000);
+    // Reversing the aggregate would only see one capped fee.
+    let aggregate = crate::transfer_fee::fee_withheld_from(config.as_ref(), 0, 38_000).unwrap();
+    assert_eq!(aggregate, 1_000);
+
+    // A single-branch route is unchanged.
+    assert_eq!(
+        crate::transfer_fee::fee_withheld_from_each(config.as_ref(), 0, &[19_000]).unwrap(),
+        crate::transfer_fee::fee_withheld_from(config.as_ref(), 0, 19_000).unwrap()
+    );
+}
+
+// ------------- Native SOL ------------- //
+
+#[test]
+fn native_mint_detection_covers_both_token_programs() {
+    use anchor_spl::{token::spl_token, token_2022::spl_token_2022};
+    assert!(crate::router::is_native_mint(&spl_token::native_mint::ID));
+    assert!(crate::router::is_native_mint(
+        &spl_token_2022::native_mint::ID
+    ));
+    assert!(!crate::router::is_native_mint(&Pubkey::new_unique()));
+
+    // Wrapping is opt-in.
+    let options = crate::RouteOptions::default();
+    assert_eq!(options.wrap_lamports, 0);
+    assert!(!options.unwrap_sol);
+}
+
+#[test]
+fn wrap_is_bounded_by_max_in_and_unwrap_pays_only_the_route_output() {
+    use crate::router::{wsol_source_lamports, WSOL_IN_SEED};
+    let rent = 2_039_280;
+
+    // The temporary source holds rent plus exactly the wrapped amount.
+    assert_eq!(
+        wsol_source_lamports(rent, 1_000_000, 1_000_000).unwrap(),
+        rent + 1_000_000
+    );
+    let err = wsol_source_lamports(rent, 1_000_001, 1_000_000).unwrap_err();
+    assert_eq!(err, AggregatorError::WrapExceedsMaxIn.into());
+
+    // One temporary account per user and direction.
+    let user = Pubkey::new_unique();
+    let (wsol_in, _) = Pubkey::find_program_address(&[WSOL_IN_SEED, user.as_ref()], &crate::ID);
+    let (wsol_out, _) =
+        Pubkey::find_program_address(&[crate::router::WSOL_OUT_SEED, user.as_ref()], &crate::ID);
+    assert_ne!(wsol_in, wsol_out);
+
+    // Unwrapping pays out the route's net output, measured as a balance delta,
+    // whatever WSOL the destination held before.
+    let settlement = crate::router::Settlement {
+        in_mint: Pubkey::default(),
+        delta_spent: 1_000,
+        delta_out: 1_000_000_000,
+        gross_out: 1_000_000_000,
+        fee_amount: 3_000_000,
+        fee_side: FeeSide::Output,
+        referral_fee: 0,
+        surplus_fee: 0,
+        fee_bps: 30,
+        transfer_fee_withheld: 0,
+        legs: 1,
+    };
+    assert_eq!(settlement.user_receive().unwrap(), 997_000_000);
+}
+
+#[test]
+fn source_token_account_is_read_by_hand() {
+    use crate::router::read_token_account;
+    let (mint, user) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let source = static_account(
+        Pubkey::new_unique(),
+        anchor_spl::token::ID,
+        token_account_data(mint, user, 42),
+    );
+    let account = read_token_account(&source).unwrap();
+    assert_eq!(
+        (account.mint, account.owner, account.amount),
+        (mint, user, 42)
+    );
+
+    // Not created yet (or not a token account at all).
+    let missing = static_account(
+        Pubkey::new_unique(),
+        anchor_lang::system_program::ID,
+        vec![],
+    );
+    let err = read_token_account(&missing).unwrap_err();
+    assert_eq!(err, AggregatorError::InvalidTokenAccount.into());
+    let uninitialised = static_account(Pubkey::new_unique(), anchor_spl::token::ID, vec![0; 165]);
+    assert!(read_token_account(&uninitialised).is_err());
+}
+
+// ------------- Adapter registry ------------- //
+
+#[test]
+fn registry_covers_every_supported_dex() {
+    assert_eq!(adapter::SUPPORTED_DEXES, ALL_DEXES);
+    assert!(
+        ALL_DEXES.len() <= crate::state::MAX_ADAPTERS,
+        "registry account too small"
+    );
+}
+
+#[test]
+fn dispatch_rejects_unregistered_and_disabled_adapters() {
+    let leg = dummy_leg(DexId::Invariant, 1, 0, 0);
+
+    let empty = AdapterRegistry {
+        entries: vec![],
+        bump: 255,
+    };
+    let err = adapter::dispatch(&leg, &[], &empty, &test_config()).unwrap_err();
+    assert!(format!("{err:?}").contains("UnknownDex"));
+
+    let mut registry = test_registry();
+    registry
+        .upsert(AdapterEntry {
+            enabled: false,
+            ..adapter::default_entry(DexId::Invariant)
+        })
+        .unwrap();
+    assert_eq!(
+        registry.entries.len(),
+        ALL_DEXES.len(),
+        "upsert duplicated entry"
+    );
+    let err = adapter::dispatch(&leg, &[], &registry, &test_config()).unwrap_err();
+    assert!(format!("{err:?}").contains("AdapterDisabled"));
+}
+
+#[test]
+fn adapter_entry_owner_whitelist() {
+    let extra = Pubkey::new_unique();
+    let entry = AdapterEntry {
+        allowed_owners: vec![extra],
+        ..adapter::default_entry(DexId::OrcaWhirlpool)
+    };
+    assert!(entry.allows_owner(&entry.program_id));
+    assert!(entry.allows_owner(&anchor_spl::token_2022::ID));
+    assert!(entry.allows_owner(&extra));
+    assert!(!entry.allows_owner(&Pubkey::new_unique()));
+}
+
+// ------------- Per-DEX pause ------------- //
+
+#[test]
+fn paused_dex_is_rejected_by_dispatch() {
+    let mut cfg = test_config();
+    cfg.set_dex_paused(DexId::OrcaWhirlpool, true);
+    assert!(cfg.is_dex_paused(DexId::OrcaWhirlpool));
+
+    let orca = dummy_leg(DexId::OrcaWhirlpool, 1, 0, 0);
+    let err = adapter::dispatch(&orca, &[], &test_registry(), &cfg).unwrap_err();
+    assert_eq!(err, AggregatorError::DexPaused.into());
+
+    // Other DEXes keep routing.
+    for &dex in ALL_DEXES.iter().filter(|d| **d != DexId::OrcaWhirlpool) {
+        assert!(!cfg.is_dex_paused(dex));
+        let leg = dummy_leg(dex, 1, 0, 0);
+        adapter::dispatch(&leg, &[], &test_registry(), &cfg).expect("unpaused dex failed");
+    }
+
+    cfg.set_dex_paused(DexId::OrcaWhirlpool, false);
+    assert_eq!(cfg.paused_dexes, 0);
+    adapter::dispatch(&orca, &[], &test_registry(), &cfg).expect("unpaused dex failed");
+}
+
+// ------------- Admin rotation ------------- //
+
+#[test]
+fn config_len_matches_serialized_size() {
+    let cfg = test_config();
+    assert_eq!(8 + cfg.try_to_vec().unwrap().len(), Config::LEN);
+}
+
+#[test]
+fn admin_transfer_requires_proposal_and_acceptance() {
+    let mut cfg = test_config();
+    let admin = cfg.admin;
+    let next = Pubkey::new_unique();
+    let stranger = Pubkey::new_unique();
+
+    // Nothing to accept yet.
+    let err = cfg.accept_admin(&next).unwrap_err();
+    assert!(format!("{err:?}").contains("NoPendingAdmin"));
+
+    // Only the current admin may propose.
+    assert!(cfg.propose_admin(&stranger, next).is_err());
+    cfg.propose_admin(&admin, next).unwrap();
+    assert_eq!(cfg.admin, admin);
+
+    // Only the proposed key may accept.
+    let err = cfg.accept_admin(&stranger).unwrap_err();
+    assert!(format!("{err:?}").contains("Unauthorized"));
+    assert_eq!(cfg.accept_admin(&next).unwrap(), admin);
+    assert_eq!(cfg.admin, next);
+    assert_eq!(cfg.pending_admin, Pubkey::default());
+
+    // The old admin has lost its rights.
+    assert!(cfg.propose_admin(&admin, admin).is_err());
+}
+
+#[test]
+fn admin_proposal_can_be_cancelled() {
+    let mut cfg = test_config();
+    let admin = cfg.admin;
+    let next = Pubkey::new_unique();
+
+    cfg.propose_admin(&admin, next).unwrap();
+    cfg.propose_admin(&admin, Pubkey::default()).unwrap();
+    assert!(cfg.accept_admin(&next).is_err());
+    assert_eq!(cfg.admin, admin);
+}
+
+#[test]
+fn treasury_defaults_to_admin_until_set() {
+    let mut cfg = test_config();
+    assert_eq!(cfg.treasury(), cfg.admin);
+
+    let multisig = Pubkey::new_unique();
+    cfg.fee_recipient = multisig;
+    assert_eq!(cfg.treasury(), multisig);
+
+    // Rotating the admin leaves fee custody where it is.
+    let admin = cfg.admin;
+    let next = Pubkey::new_unique();
+    cfg.propose_admin(&admin, next).unwrap();
+    cfg.accept_admin(&next).unwrap();
+    assert_eq!(cfg.treasury(), multisig);
+}
+
+// ------------- Fee tiers ------------- //
+
+#[test]
+fn fee_tier_matches_pair_or_any_input() {
+    let (usdc, usdt, sol) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    let pair = crate::state::FeeTier {
+        in_mint: usdt,
+        out_mint: usdc,
+        fee_bps: 1,
+        bump: 255,
+    };
+    assert!(pair.applies_to(&usdt, &usdc));
+    assert!(!pair.applies_to(&sol, &usdc));
+    assert!(!pair.applies_to(&usdc, &usdt));
+
+    let any_input = crate::state::FeeTier {
+        in_mint: Pubkey::default(),
+        ..pair
+    };
+    assert!(any_input.applies_to(&usdt, &usdc));
+    assert!(any_input.applies_to(&sol, &usdc));
+    assert!(!any_input.applies_to(&usdc, &sol));
+}
+
+/// `[b"fee_tier", in_mint, out_mint]` PDA, populated when `fee_bps` is set.
+fn fee_tier_account(
+    in_mint: Pubkey,
+    out_mint: Pubkey,
+    fee_bps: Option<u16>,
+) -> AccountInfo<'static> {
+    let (address, bump) = Pubkey::find_program_address(
+        &[b"fee_tier", in_mint.as_ref(), out_mint.as_ref()],
+        &crate::ID,
+    );
+    match fee_bps {
+        Some(fee_bps) => {
+            let mut data = Vec::new();
+            crate::state::FeeTier {
+                in_mint,
+                out_mint,
+                fee_bps,
+                bump,
+            }
+            .try_serialize(&mut data)
+            .unwrap();
+            static_account(address, crate::ID, data)
+        }
+        None => static_account(address, anchor_lang::system_program::ID, vec![]),
+    }
+}
+
+#[test]
+fn existing_fee_tier_always_applies() {
+    use crate::router::resolve_fee_bps;
+    let (usdt, usdc) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let any = Pubkey::default();
+
+    let pair = fee_tier_account(usdt, usdc, Some(1));
+    let no_pair = fee_tier_account(usdt, usdc, None);
+    let any_input = fee_tier_account(any, usdc, Some(5));
+    let no_any_input = fee_tier_account(any, usdc, None);
+
+    // The pair tier wins over the any-input tier, which wins over the config.
+    assert_eq!(
+        resolve_fee_bps(&pair, &any_input, &usdt, &usdc, 30).unwrap(),
+        1
+    );
+    assert_eq!(
+        resolve_fee_bps(&no_pair, &any_input, &usdt, &usdc, 30).unwrap(),
+        5
+    );
+    assert_eq!(
+        resolve_fee_bps(&no_pair, &no_any_input, &usdt, &usdc, 30).unwrap(),
+        30
+    );
+}
+
+#[test]
+fn route_omitting_an_existing_fee_tier_is_rejected() {
+    use crate::router::resolve_fee_bps;
+    let (usdt, usdc) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let any = Pubkey::default();
+    let no_any_input = fee_tier_account(any, usdc, None);
+    let stand_in = static_account(
+        Pubkey::new_unique(),
+        anchor_lang::system_program::ID,
+        vec![],
+    );
+
+    // An empty account elsewhere cannot stand in for the pair's tier PDA...
+    let err = resolve_fee_bps(&stand_in, &no_any_input, &usdt, &usdc, 30).unwrap_err();
+    assert_eq!(err, AggregatorError::FeeTierMismatch.into());
+    // ...nor for the any-input one.
+    let no_pair = fee_tier_account(usdt, usdc, None);
+    let err = resolve_fee_bps(&no_pair, &stand_in, &usdt, &usdc, 30).unwrap_err();
+    assert_eq!(err, AggregatorError::FeeTierMismatch.into());
+
+    // Another pair's tier is no substitute either.
+    let other = fee_tier_account(Pubkey::new_unique(), usdc, Some(0));
+    let err = resolve_fee_bps(&other, &no_any_input, &usdt, &usdc, 30).unwrap_err();
+    assert_eq!(err, AggregatorError::FeeTierMismatch.into());
+}
+
+// ------------- Fee withdrawal ------------- //
+
+/// Initialised SPL mint with 6 decimals.
+fn packed_mint() -> Vec<u8> {
+    use anchor_lang::solana_program::program_pack::Pack;
+    use anchor_spl::token::spl_token::state::Mint;
+    let mut data = vec![0u8; Mint::LEN];
+    Mint {
+        decimals: 6,
+        is_initialized: true,
+        ..Default::default()
+    }
+    .pack_into_slice(&mut data);
+    data
+}
+
+#[test]
+fn withdraw_fees_takes_vault_mint_destination_triples() {
+    let token = anchor_spl::token::ID;
+    let accounts: Vec<AccountInfo> = (0..7)
+        .map(|_| static_account(Pubkey::new_unique(), token, vec![]))
+        .collect();
+
+    assert_eq!(crate::fee_vault_triples(&accounts[..6]).unwrap().len(), 2);
+    assert_eq!(crate::fee_vault_triples(&accounts[..3]).unwrap().len(), 1);
+    for len in [0, 1, 4, 7] {
+        let err = crate::fee_vault_triples(&accounts[..len]).unwrap_err();
+        assert_eq!(err, AggregatorError::RemainingAccountsMismatch.into());
+    }
+}
+
+#[test]
+fn withdraw_fees_only_sweeps_pda_vaults_signed_by_the_fee_authority() {
+    let token = anchor_spl::token::ID;
+    let (fee_authority, bump) = Pubkey::find_program_address(&[b"fee_authority"], &crate::ID);
+    let treasury = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let (vault, _) = Pubkey::find_program_address(&[b"fee_vault", mint.as_ref()], &crate::ID);
+
+    let authority = static_account(fee_authority, anchor_lang::system_program::ID, vec![]);
+    let token_program = static_account(token, Pubkey::new_unique(), vec![]);
+    let triple = |vault: Pubkey,
+                  vault_owner: Pubkey,
+                  destination_owner: Pubkey|
+     -> &'static [AccountInfo<'static>] {
+        vec![
+            static_account(vault, token, token_account_data(mint, vault_owner, 500)),
+            static_account(mint, token, packed_mint()),
+            static_account(
+                Pubkey::new_unique(),
+                token,
+                token_account_data(mint, destination_owner, 0),
+            ),
+        ]
+        .leak()
+    };
+    let withdraw = |triple, bump| {
+        crate::withdraw_fee_vault(triple, &authority, bump, treasury, &token_program)
+    };
+
+    assert_eq!(
+        withdraw(triple(vault, fee_authority, treasury), bump).unwrap(),
+        500
+    );
+
+    // A token account owned by the authority but not at the vault PDA.
+    let err = withdraw(triple(Pubkey::new_unique(), fee_authority, treasury), bump).unwrap_err();
+    assert_eq!(err, AggregatorError::InvalidFeeVault.into());
+
+    // The vault PDA address holding an account of another owner.
+    let err = withdraw(triple(vault, Pubkey::new_unique(), treasury), bump).unwrap_err();
+    assert_eq!(err, AggregatorError::FeeVaultOwnerMismatch.into());
+
+    // Seeds that do not derive the fee authority cannot sign.
+    let err = withdraw(triple(vault, fee_authority, treasury), bump.wrapping_sub(1)).unwrap_err();
+    assert_eq!(err, AggregatorError::FeeVaultOwnerMismatch.into());
+
+    // Fees only go to the treasury.
+    let err = withdraw(triple(vault, fee_authority, Pubkey::new_unique()), bump).unwrap_err();
+    assert_eq!(err, AggregatorError::FeeDestinationMismatch.into());
+}
+
+// ------------- Fee side ------------- //
+
+#[test]
+fn input_fee_is_charged_on_planned_input() {
+    use crate::router::{input_fee, planned_input};
+    let (usdc, sol) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let mut first = mint_leg(DexId::OrcaWhirlpool, usdc, sol);
+    first.in_amount = 6_000;
+    let mut second = mint_leg(DexId::SolarCp, usdc, sol);
+    second.in_amount = 4_000;
+    let mut hop = mint_leg(DexId::SolarCp, sol, usdc);
+    hop.in_amount = 123;
+
+    // Only the first leg of each branch draws on `user_source`.
+    let planned = planned_input(&[&[first.clone()], &[second, hop]]).unwrap();
+    assert_eq!(planned, 10_000);
+    assert_eq!(input_fee(planned, 30, 10_030).unwrap(), 30);
+    assert_eq!(input_fee(9_000, 30, 10_030).unwrap(), 27);
+    assert_eq!(input_fee(0, 30, 0).unwrap(), 0);
+
+    // The fee counts towards `user_max_in`.
+    let err = input_fee(10_000, 30, 10_029).unwrap_err();
+    assert_eq!(err, AggregatorError::TooManyTokensSpent.into());
+}
+
+#[test]
+fn fee_side_is_a_config_setting() {
+    let mut cfg = test_config();
+    assert_eq!(cfg.fee_side, FeeSide::Output);
+    cfg.fee_side = FeeSide::Input;
+    let bytes = cfg.try_to_vec().unwrap();
+    assert_eq!(8 + bytes.len(), Config::LEN);
+    let restored = Config::try_from_slice(&bytes).unwrap();
+    assert_eq!(restored.fee_side, FeeSide::Input);
+}
+
+#[test]
+fn input_side_fee_leaves_output_untouched() {
+    let settlement = crate::router::Settlement {
+        in_mint: Pubkey::default(),
+        delta_spent: 1_003,
+        delta_out: 500,
+        gross_out: 500,
+        fee_amount: 3,
+        fee_side: FeeSide::Input,
+        referral_fee: 0,
+        surplus_fee: 0,
+        fee_bps: 30,
+        transfer_fee_withheld: 0,
+        legs: 1,
+    };
+    assert_eq!(settlement.user_receive().unwrap(), 500);
+
+    let output_side = crate::router::Settlement {
+        fee_side: FeeSide::Output,
+        ..settlement
+    };
+    assert_eq!(output_side.user_receive().unwrap(), 497);
+}
+
+// ------------- Positive slippage ------------- //
+
+#[test]
+fn surplus_fee_only_applies_above_quote() {
+    use crate::router::surplus_fee;
+    // No quote, or delivery at/below quote: nothing captured.
+    assert_eq!(surplus_fee(1_000, 0, 5_000).unwrap(), 0);
+    assert_eq!(surplus_fee(1_000, 1_000, 5_000).unwrap(), 0);
+    assert_eq!(surplus_fee(900, 1_000, 5_000).unwrap(), 0);
+    // Half of a 200-token surplus.
+    assert_eq!(surplus_fee(1_200, 1_000, 5_000).unwrap(), 100);
+    assert_eq!(surplus_fee(1_200, 1_000, 0).unwrap(), 0);
+}
+
+#[test]
+fn surplus_fee_is_deducted_from_user_output() {
+    let settlement = crate::router::Settlement {
+        in_mint: Pubkey::default(),
+        delta_spent: 1_000,
+        delta_out: 1_200,
+        gross_out: 1_200,
+        fee_amount: 3,
+        fee_side: FeeSide::Output,
+        referral_fee: 1,
+        surplus_fee: 100,
+        fee_bps: 30,
+        transfer_fee_withheld: 0,
+        legs: 1,
+    };
+    assert_eq!(settlement.user_receive().unwrap(), 1_097);
+    assert_eq!(settlement.protocol_fee().unwrap(), 2);
+}
+
+#[test]
+fn surplus_quote_is_pinned_to_the_minimum_output() {
+    use crate::router::{pin_quote, required_gross_out};
+    let floor = required_gross_out(997, FeeSide::Output, 30).unwrap();
+    assert_eq!(floor, 999);
+    assert_eq!(required_gross_out(997, FeeSide::Input, 30).unwrap(), 997);
+
+    // No quote defaults to the floor; a lowballed quote is rejected.
+    assert_eq!(pin_quote(0, floor).unwrap(), floor);
+    assert_eq!(pin_quote(1_100, floor).unwrap(), 1_100);
+    let err = pin_quote(998, floor).unwrap_err();
+    assert_eq!(err, AggregatorError::QuoteBelowMinOut.into());
+}
+
+#[test]
+fn surplus_fee_never_breaks_the_minimum_output() {
+    use crate::router::{compute_fee, pin_quote, required_gross_out, surplus_fee};
+    for (min_out, fee_bps) in [(997u64, 30u16), (1u64, 30), (123_456, 100), (50_000, 0)] {
+        let floor = required_gross_out(min_out, FeeSide::Output, fee_bps).unwrap();
+        let quoted_out = pin_quote(0, floor).unwrap();
+        for delta_out in floor..floor + 500 {
+            let settlement = crate::router::Settlement {
+                in_mint: Pubkey::default(),
+                delta_spent: 0,
+                delta_out,
+                gross_out: delta_out,
+                fee_amount: compute_fee(delta_out, fee_bps).unwrap(),
+                fee_side: FeeSide::Output,
+                referral_fee: 0,
+                surplus_fee: surplus_fee(delta_out, quoted_out, 5_000).unwrap(),
+                fee_bps,
+                transfer_fee_withheld: 0,
+                legs: 1,
+            };
+            assert!(
+                settlement.user_receive().unwrap() >= min_out,
+                "min_out {min_out} broken at {delta_out}"
+            );
+        }
+    }
+}
+
+// ------------- Deadline ------------- //
+
+#[test]
+fn deadline_rejects_late_routes() {
+    use crate::router::check_deadline;
+    let none = RouteOptions::default();
+    check_deadline(&none, u64::MAX, i64::MAX).unwrap();
+
+    let by_slot = RouteOptions {
+        valid_until_slot: 100,
+        ..Default::default()
+    };
+    check_deadline(&by_slot, 100, 0).unwrap();
+    let err = check_deadline(&by_slot, 101, 0).unwrap_err();
+    assert!(format!("{err:?}").contains("Expired"));
+
+    let by_ts = RouteOptions {
+        valid_until_ts: 1_700_000_000,
+        ..Default::default()
+    };
+    check_deadline(&by_ts, 101, 1_700_000_000).unwrap();
+    assert!(check_deadline(&by_ts, 0, 1_700_000_001).is_err());
+
+    // Both limits apply when both are set.
+    let both = RouteOptions {
+        valid_until_slot: 100,
+        valid_until_ts: 1_700_000_000,
+        ..Default::default()
+    };
+    assert!(check_deadline(&both, 101, 0).is_err());
+    assert!(check_deadline(&both, 0, 1_700_000_001).is_err());
+}
+
+// ------------- Oracle guard ------------- //
+
+fn price_feed(mint: &Pubkey, price: i64, expo: i32, publish_slot: u64) -> crate::oracle::PriceFeed {
+    crate::oracle::PriceFeed {
+        magic: crate::oracle::PRICE_FEED_MAGIC,
+        mint: mint.to_bytes(),
+        price,
+        expo,
+        _padding: [0; 4],
+        publish_slot,
+    }
+}
+
+#[test]
+fn route_without_feeds_is_rejected_when_oracle_is_required() {
+    use crate::router::oracle_guard_enabled;
+    let mut cfg = test_config();
+    assert!(oracle_guard_enabled(&cfg, true, true).unwrap());
+    assert!(!oracle_guard_enabled(&cfg, false, false).unwrap());
+
+    cfg.require_oracle = true;
+    assert!(oracle_guard_enabled(&cfg, true, true).unwrap());
+    let err = oracle_guard_enabled(&cfg, false, false).unwrap_err();
+    assert_eq!(err, AggregatorError::OracleRequired.into());
+
+    // A single feed is rejected either way.
+    for require_oracle in [false, true] {
+        cfg.require_oracle = require_oracle;
+        let err = oracle_guard_enabled(&cfg, true, false).unwrap_err();
+        assert_eq!(err, AggregatorError::MissingOptionalAccount.into());
+    }
+}
+
+#[test]
+fn mock_price_feed_is_read_and_validated() {
+    use crate::oracle::read_price;
+    let (key, mint, oracle) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    let feed = price_feed(&mint, 150_000_000, -6, 1_000);
+    let ai = static_account(key, oracle, bytemuck::bytes_of(&feed).to_vec());
+
+    let read = read_price(&ai, &mint, &oracle, 1_010, 10).unwrap();
+    assert_eq!((read.price, read.expo), (150_000_000, -6));
+
+    // Stale, wrong mint, wrong owner.
+    let err = read_price(&ai, &mint, &oracle, 1_011, 10).unwrap_err();
+    assert!(format!("{err:?}").contains("StalePrice"));
+    let err = read_price(&ai, &Pubkey::new_unique(), &oracle, 1_010, 10).unwrap_err();
+    assert!(format!("{err:?}").contains("InvalidPriceFeed"));
+    assert!(read_price(&ai, &mint, &key, 1_010, 10).is_err());
+}
+
+#[test]
+fn oracle_deviation_rejects_only_bad_fills() {
+    use crate::oracle::check_deviation;
+    let (sol, usdc) = (Pubkey::new_unique(), Pubkey::new_unique());
+    // SOL = 150 (expo -8), USDC = 1 (expo -6).
+    let sol_price = price_feed(&sol, 15_000_000_000, -8, 0);
+    let usdc_price = price_feed(&usdc, 1_000_000, -6, 0);
+
+    // 1 SOL (9 decimals) for 150 USDC (6 decimals): exactly the oracle rate.
+    check_deviation(1_000_000_000, 9, &sol_price, 150_000_000, 6, &usdc_price, 0).unwrap();
+    // 1 % short passes with a 1 % threshold, fails with 0.5 %.
+    check_deviation(
+        1_000_000_000,
+        9,
+        &sol_price,
+        148_500_000,
+        6,
+        &usdc_price,
+        100,
+    )
+    .unwrap();
+    let err = check_deviation(
+        1_000_000_000,
+        9,
+        &sol_price,
+        148_500_000,
+        6,
+        &usdc_price,
+        50,
+    )
+    .unwrap_err();
+    assert!(format!("{err:?}").contains("PriceDeviationExceeded"));
+    // Beating the oracle is never rejected, in either direction of the pair.
+    check_deviation(1_000_000_000, 9, &sol_price, 160_000_000, 6, &usdc_price, 0).unwrap();
+    check_deviation(150_000_000, 6, &usdc_price, 1_000_000_000, 9, &sol_price, 0).unwrap();
+    assert!(check_deviation(150_000_000, 6, &usdc_price, 900_000_000, 9, &sol_price, 100).is_err());
+}
+
+#[test]
+fn oracle_rejects_zero_amounts() {
+    use crate::oracle::check_deviation;
+    let (sol, usdc) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let sol_price = price_feed(&sol, 15_000_000_000, -8, 0);
+    let usdc_price = price_feed(&usdc, 1_000_000, -6, 0);
+
+    // Nothing spent would otherwise pass any threshold; nothing received would
+    // pass a 100 % one.
+    let err = check_deviation(0, 9, &sol_price, 150_000_000, 6, &usdc_price, 0).unwrap_err();
+    assert_eq!(err, AggregatorError::ZeroOracleAmount.into());
+    let err = check_deviation(1_000_000_000, 9, &sol_price, 0, 6, &usdc_price, 10_000).unwrap_err();
+    assert_eq!(err, AggregatorError::ZeroOracleAmount.into());
+}
+
+// ------------- Orca account validation ------------- //
+
+/// Runs `A::validate_accounts` over data-less accounts given as
+/// `(key, owner, executable)`.
+fn validate_layout<A: adapter::DexAdapter>(
+    data: &[u8],
+    layout: &[(Pubkey, Pubkey, bool)],
+    entry: &AdapterEntry,
+) -> Result<()> {
+    A::validate_accounts(data, &layout_accounts(layout), entry)
+}
+
+#[test]
+fn orca_validates_swap_v2_accounts_by_position_and_owner() {
+    use adapter::orca::{OrcaWhirlpool, MEMO_PROGRAM_ID, ORCA_WHIRLPOOL_PROGRAM_ID};
+
+    let token = anchor_spl::token::ID;
+    let system = anchor_lang::system_program::ID;
+    let loader = Pubkey::new_unique();
+    let orca = ORCA_WHIRLPOOL_PROGRAM_ID;
+    // (key, owner, executable) in `swap_v2` order.
+    let layout: Vec<(Pubkey, Pubkey, bool)> = vec![
+        (token, loader, true),                      // token_program_a
+        (anchor_spl::token_2022::ID, loader, true), // token_program_b
+        (MEMO_PROGRAM_ID, loader, true),            // memo_program
+        (Pubkey::new_unique(), system, false),      // token_authority
+        (Pubkey::new_unique(), orca, false),        // whirlpool
+        (Pubkey::new_unique(), token, false),       // token_mint_a
+        (Pubkey::new_unique(), token, false),       // token_mint_b
+        (Pubkey::new_unique(), token, false),       // token_owner_account_a
+        (Pubkey::new_unique(), token, false),       // token_vault_a
+        (Pubkey::new_unique(), token, false),       // token_owner_account_b
+        (Pubkey::new_unique(), token, false),       // token_vault_b
+        (Pubkey::new_unique(), orca, false),        // tick_array_0
+        (Pubkey::new_unique(), orca, false),        // tick_array_1
+        (Pubkey::new_unique(), orca, false),        // tick_array_2
+        (Pubkey::new_unique(), system, false),      // oracle (uninitialised PDA)
+    ];
+    let entry = adapter::default_entry(DexId::OrcaWhirlpool);
+    let mut data = vec![0u8; 42];
+    data[..8].copy_from_slice(&adapter::orca::SWAP_V2_DISCRIMINATOR);
+
+    let validate =
+        |layout: &[(Pubkey, Pubkey, bool)]| validate_layout::<OrcaWhirlpool>(&data, layout, &entry);
+
+    validate(&layout).expect("valid swap_v2 accounts rejected");
+    assert!(
+        validate(&layout[..14]).is_err(),
+        "short account list accepted"
+    );
+
+    // A tick array that is not a Whirlpool account.
+    let mut bad = layout.clone();
+    bad[12].1 = token;
+    assert!(validate(&bad).is_err());
+
+    // A vault that is not a token account.
+    let mut bad = layout.clone();
+    bad[8].1 = orca;
+    assert!(validate(&bad).is_err());
+
+    // An arbitrary program in place of Memo.
+    let mut bad = layout.clone();
+    bad[2].0 = Pubkey::new_unique();
+    assert!(validate(&bad).is_err());
+
+    // An account owned by an unrelated program.
+    let mut bad = layout.clone();
+    bad[3].1 = Pubkey::new_unique();
+    assert!(validate(&bad).is_err());
+}
+
+#[test]
+fn orca_accepts_uninitialised_tick_arrays_past_the_current_one() {
+    use adapter::orca::{OrcaWhirlpool, ORCA_WHIRLPOOL_PROGRAM_ID, TWO_HOP_SWAP_DISCRIMINATOR};
+    use adapter::DexAdapter;
+
+    let token = anchor_spl::token::ID;
+    let system = anchor_lang::system_program::ID;
+    let loader = Pubkey::new_unique();
+    let orca = ORCA_WHIRLPOOL_PROGRAM_ID;
+    let entry = adapter::default_entry(DexId::OrcaWhirlpool);
+    let account = |owner| (Pubkey::new_unique(), owner, false);
+
+    // `swap`: tick_array_1/2 sit at 8/9 and were never initialised.
+    let mut layout: Vec<(Pubkey, Pubkey, bool)> = vec![
+        (token, loader, true), // token_program
+        account(system),       // token_authority
+        account(orca),         // whirlpool
+        account(token),        // token_owner_account_a
+        account(token),        // token_vault_a
+        account(token),        // token_owner_account_b
+        account(token),        // token_vault_b
+        account(orca),         // tick_array_0
+        account(system),       // tick_array_1
+        account(system),       // tick_array_2
+        account(system),       // oracle
+    ];
+    let mut data = vec![0u8; 42];
+    data[..8].copy_from_slice(&adapter::SWAP_DISCRIMINATOR);
+    validate_layout::<OrcaWhirlpool>(&data, &layout, &entry)
+        .expect("uninitialised tick_array_1/2 rejected");
+
+    // The current tick array must exist.
+    layout[7].1 = system;
+    assert!(validate_layout::<OrcaWhirlpool>(&data, &layout, &entry).is_err());
+    layout[7].1 = orca;
+
+    // A system-owned account holding data is not an uninitialised tick array.
+    let mut accounts = layout_accounts(&layout);
+    accounts[8] = static_account(layout[8].0, system, vec![1u8; 8]);
+    assert!(OrcaWhirlpool::validate_accounts(&data, &accounts, &entry).is_err());
+
+    // `two_hop_swap`: each hop's first tick array (12, 15) must exist.
+    let mut layout: Vec<(Pubkey, Pubkey, bool)> = vec![
+        (token, loader, true), // token_program
+        account(system),       // token_authority
+        account(orca),         // whirlpool_one
+        account(orca),         // whirlpool_two
+    ];
+    layout.extend((4..12).map(|_| account(token)));
+    layout.extend([
+        account(orca),   // tick_array_one_0
+        account(system), // tick_array_one_1
+        account(system), // tick_array_one_2
+        account(orca),   // tick_array_two_0
+        account(system), // tick_array_two_1
+        account(system), // tick_array_two_2
+        account(system), // oracle_one
+        account(system), // oracle_two
+    ]);
+    data[..8].copy_from_slice(&TWO_HOP_SWAP_DISCRIMINATOR);
+    validate_layout::<OrcaWhirlpool>(&data, &layout, &entry)
+        .expect("uninitialised two-hop tick arrays rejected");
+    layout[15].1 = system;
+    assert!(validate_layout::<OrcaWhirlpool>(&data, &layout, &entry).is_err());
+}
+
+// ------------- Raydium adapters ------------- //
+
+#[test]
+fn raydium_amm_v4_encodes_native_swaps() {
+    use adapter::{raydium_amm, raydium_amm::RaydiumAmmV4, DexAdapter};
+    let params = |base_input, v2| SwapParams::RaydiumAmmV4 {
+        amount: 5,
+        other_amount_threshold: 9,
+        base_input,
+        v2,
+    };
+    let expect = |tag: u8, first: u64, second: u64| {
+        [
+            vec![tag],
+            first.to_le_bytes().to_vec(),
+            second.to_le_bytes().to_vec(),
+        ]
+        .concat()
+    };
+
+    let data = RaydiumAmmV4::encode(&params(true, false)).unwrap();
+    assert_eq!(data, expect(raydium_amm::SWAP_BASE_IN_TAG, 5, 9));
+    assert_eq!(RaydiumAmmV4::token_accounts(&data).unwrap(), (15, 16));
+    RaydiumAmmV4::check_instruction(&data).unwrap();
+
+    // `swap_base_out(max_amount_in, amount_out)`.
+    let data = RaydiumAmmV4::encode(&params(false, true)).unwrap();
+    assert_eq!(data, expect(raydium_amm::SWAP_BASE_OUT_V2_TAG, 9, 5));
+    assert_eq!(RaydiumAmmV4::token_accounts(&data).unwrap(), (5, 6));
+
+    // Deposits, withdrawals and pool admin are not forwarded.
+    let deposit = expect(3, 5, 9);
+    assert!(RaydiumAmmV4::check_instruction(&deposit).is_err());
+    assert!(RaydiumAmmV4::token_accounts(&deposit).is_err());
+    assert!(RaydiumAmmV4::encode(&dummy_params(DexId::RaydiumCpmm, 5, 9)).is_err());
+}
+
+#[test]
+fn raydium_amm_v4_validates_accounts_by_position_and_owner() {
+    use adapter::raydium_amm::{
+        RaydiumAmmV4, OPENBOOK_PROGRAM_ID, RAYDIUM_AMM_V4_PROGRAM_ID, SWAP_BASE_IN_TAG,
+        SWAP_BASE_IN_V2_TAG,
+    };
+
+    let token = anchor_spl::token::ID;
+    let system = anchor_lang::system_program::ID;
+    let loader = Pubkey::new_unique();
+    let amm = RAYDIUM_AMM_V4_PROGRAM_ID;
+    let book = OPENBOOK_PROGRAM_ID;
+    // (key, owner, executable) in legacy `swap_base_in` order.
+    let layout: Vec<(Pubkey, Pubkey, bool)> = vec![
+        (token, loader, true),                 // token_program
+        (Pubkey::new_unique(), amm, false),    // amm
+        (Pubkey::new_unique(), system, false), // amm_authority
+        (Pubkey::new_unique(), book, false),   // amm_open_orders
+        (Pubkey::new_unique(), amm, false),    // amm_target_orders
+        (Pubkey::new_unique(), token, false),  // pool_coin_vault
+        (Pubkey::new_unique(), token, false),  // pool_pc_vault
+        (book, loader, true),                  // serum_program
+        (Pubkey::new_unique(), book, false),   // serum_market
+        (Pubkey::new_unique(), book, false),   // serum_bids
+        (Pubkey::new_unique(), book, false),   // serum_asks
+        (Pubkey::new_unique(), book, false),   // serum_event_queue
+        (Pubkey::new_unique(), token, false),  // serum_coin_vault
+        (Pubkey::new_unique(), token, false),  // serum_pc_vault
+        (Pubkey::new_unique(), system, false), // serum_vault_signer
+        (Pubkey::new_unique(), token, false),  // user_source
+        (Pubkey::new_unique(), token, false),  // user_destination
+        (Pubkey::new_unique(), system, false), // user_owner
+    ];
+    let entry = adapter::default_entry(DexId::RaydiumAmmV4);
+    let data = [SWAP_BASE_IN_TAG];
+    let validate =
+        |layout: &[(Pubkey, Pubkey, bool)]| validate_layout::<RaydiumAmmV4>(&data, layout, &entry);
+
+    validate(&layout).expect("valid swap_base_in accounts rejected");
+    // The 17-account layout without target orders is not supported.
+    assert!(validate(&layout[..17]).is_err());
+
+    // A pool that is not an AMM v4 account.
+    let mut bad = layout.clone();
+    bad[1].1 = book;
+    assert!(validate(&bad).is_err());
+
+    // A Token-2022 vault.
+    let mut bad = layout.clone();
+    bad[5].1 = anchor_spl::token_2022::ID;
+    assert!(validate(&bad).is_err());
+
+    // An arbitrary program in place of OpenBook.
+    let mut bad = layout.clone();
+    bad[7].0 = Pubkey::new_unique();
+    assert!(validate(&bad).is_err());
+
+    // The v2 swap drops the order-book accounts.
+    let v2: Vec<_> = [0, 1, 2, 5, 6, 15, 16, 17]
+        .iter()
+        .map(|&i| layout[i])
+        .collect();
+    validate_layout::<RaydiumAmmV4>(&[SWAP_BASE_IN_V2_TAG], &v2, &entry)
+        .expect("valid swap_base_in_v2 accounts rejected");
+}
+
+/// Account checks shared by the CP-Swap adapters, Raydium CPMM and its Solar CP
+/// fork, with `cpmm` the pool program of `dex`.
+fn validate_cp_swap_layout<A: adapter::DexAdapter>(dex: DexId, cpmm: Pubkey) {
+    let token = anchor_spl::token::ID;
+    let token_2022 = anchor_spl::token_2022::ID;
+    let system = anchor_lang::system_program::ID;
+    let loader = Pubkey::new_unique();
+    // (key, owner, executable) in `swap_base_input` order.
+    let layout: Vec<(Pubkey, Pubkey, bool)> = vec![
+        (Pubkey::new_unique(), system, false),     // payer
+        (Pubkey::new_unique(), system, false),     // authority
+        (Pubkey::new_unique(), cpmm, false),       // amm_config
+        (Pubkey::new_unique(), cpmm, false),       // pool_state
+        (Pubkey::new_unique(), token, false),      // input_token_account
+        (Pubkey::new_unique(), token_2022, false), // output_token_account
+        (Pubkey::new_unique(), token, false),      // input_vault
+        (Pubkey::new_unique(), token_2022, false), // output_vault
+        (token, loader, true),                     // input_token_program
+        (token_2022, loader, true),                // output_token_program
+        (Pubkey::new_unique(), token, false),      // input_token_mint
+        (Pubkey::new_unique(), token_2022, false), // output_token_mint
+        (Pubkey::new_unique(), cpmm, false),       // observation_state
+    ];
+    let entry = adapter::default_entry(dex);
+    let data = A::encode(&dummy_params(dex, 5, 3)).unwrap();
+    assert_eq!(data[..8], adapter::solar_cp::SWAP_BASE_INPUT_DISCRIMINATOR);
+    assert_eq!(A::token_accounts(&data).unwrap(), (4, 5));
+    let validate = |layout: &[(Pubkey, Pubkey, bool)]| validate_layout::<A>(&data, layout, &entry);
+
+    validate(&layout).expect("valid swap_base_input accounts rejected");
+    assert!(validate(&layout[..12]).is_err());
+
+    // A pool or observation account that is not a CPMM account.
+    for i in [3, 12] {
+        let mut bad = layout.clone();
+        bad[i].1 = token;
+        assert!(validate(&bad).is_err());
+    }
+
+    // A vault that is not a token account.
+    let mut bad = layout.clone();
+    bad[6].1 = cpmm;
+    assert!(validate(&bad).is_err());
+
+    // An arbitrary program in place of a token program.
+    let mut bad = layout.clone();
+    bad[9].0 = Pubkey::new_unique();
+    assert!(validate(&bad).is_err());
+}
+
+#[test]
+fn raydium_cpmm_validates_accounts_by_position_and_owner() {
+    use adapter::raydium_cpmm::{RaydiumCpmm, RAYDIUM_CPMM_PROGRAM_ID};
+    validate_cp_swap_layout::<RaydiumCpmm>(DexId::RaydiumCpmm, RAYDIUM_CPMM_PROGRAM_ID);
+}
+
+#[test]
+fn solar_cp_validates_accounts_like_cpmm() {
+    use adapter::solar_cp::{SolarCp, SOLAR_CP_PROGRAM_ID};
+    validate_cp_swap_layout::<SolarCp>(DexId::SolarCp, SOLAR_CP_PROGRAM_ID);
+}
+
+#[test]
+fn raydium_clmm_validates_pool_and_tick_array_accounts() {
+    use adapter::orca::MEMO_PROGRAM_ID;
+    use adapter::raydium_clmm::{RaydiumClmm, RAYDIUM_CLMM_PROGRAM_ID};
+    use adapter::DexAdapter;
+
+    let token = anchor_spl::token::ID;
+    let token_2022 = anchor_spl::token_2022::ID;
+    let system = anchor_lang::system_program::ID;
+    let loader = Pubkey::new_unique();
+    let clmm = RAYDIUM_CLMM_PROGRAM_ID;
+    // (key, owner, executable) in `swap_v2` order.
+    let mut layout: Vec<(Pubkey, Pubkey, bool)> = vec![
+        (Pubkey::new_unique(), system, false), // payer
+        (Pubkey::new_unique(), clmm, false),   // amm_config
+        (Pubkey::new_unique(), clmm, false),   // pool_state
+        (Pubkey::new_unique(), token, false),  // input_token_account
+        (Pubkey::new_unique(), token, false),  // output_token_account
+        (Pubkey::new_unique(), token, false),  // input_vault
+        (Pubkey::new_unique(), token, false),  // output_vault
+        (Pubkey::new_unique(), clmm, false),   // observation_state
+        (token, loader, true),                 // token_program
+        (token_2022, loader, true),            // token_program_2022
+        (MEMO_PROGRAM_ID, loader, true),       // memo_program
+        (Pubkey::new_unique(), token, false),  // input_vault_mint
+        (Pubkey::new_unique(), token, false),  // output_vault_mint
+    ];
+    let entry = adapter::default_entry(DexId::RaydiumClmm);
+    let data = RaydiumClmm::encode(&dummy_params(DexId::RaydiumClmm, 5, 3)).unwrap();
+    assert_eq!(data[..8], adapter::solar_clmm::SWAP_V2_DISCRIMINATOR);
+    assert_eq!(data.len(), 8 + 8 + 8 + 16 + 1);
+    assert_eq!(RaydiumClmm::token_accounts(&data).unwrap(), (3, 4));
+    let validate =
+        |layout: &[(Pubkey, Pubkey, bool)]| validate_layout::<RaydiumClmm>(&data, layout, &entry);
+
+    // Without a single tick array the swap cannot run.
+    assert!(validate(&layout).is_err());
+
+    // Bitmap extension followed by any number of tick arrays.
+    for _ in 0..4 {
+        layout.push((Pubkey::new_unique(), clmm, false));
+        validate(&layout).expect("valid swap_v2 accounts rejected");
+    }
+
+    // A pool state that is not a CLMM account.
+    let mut bad = layout.clone();
+    bad[2].1 = Pubkey::new_unique();
+    assert!(validate(&bad).is_err());
+
+    // A trailing tick array owned by someone else, even an allowed owner.
+    let mut bad = layout.clone();
+    bad.last_mut().unwrap().1 = token;
+    assert!(validate(&bad).is_err());
+
+    // Token programs swapped around.
+    let mut bad = layout.clone();
+    bad.swap(8, 9);
+    assert!(validate(&bad).is_err());
+
+    // An arbitrary program in place of Memo.
+    let mut bad = layout.clone();
+    bad[10].0 = Pubkey::new_unique();
+    assert!(validate(&bad).is_err());
+}
+
+// ------------- Meteora DLMM ------------- //
+
+/// Accounts of a DLMM `swap` as `(key, owner, executable)`, without bin arrays
+/// and with both optional accounts set to `None`.
+fn dlmm_swap_layout() -> Vec<(Pubkey, Pubkey, bool)> {
+    use adapter::meteora_dlmm::METEORA_DLMM_PROGRAM_ID;
+    let token = anchor_spl::token::ID;
+    let system = anchor_lang::system_program::ID;
+    let loader = Pubkey::new_unique();
+    let dlmm = METEORA_DLMM_PROGRAM_ID;
+    vec![
+        (Pubkey::new_unique(), dlmm, false),   // lb_pair
+        (dlmm, loader, true),                  // bin_array_bitmap_extension: None
+        (Pubkey::new_unique(), token, false),  // reserve_x
+        (Pubkey::new_unique(), token, false),  // reserve_y
+        (Pubkey::new_unique(), token, false),  // user_token_in
+        (Pubkey::new_unique(), token, false),  // user_token_out
+        (Pubkey::new_unique(), token, false),  // token_x_mint
+        (Pubkey::new_unique(), token, false),  // token_y_mint
+        (Pubkey::new_unique(), dlmm, false),   // oracle
+        (dlmm, loader, true),                  // host_fee_in: None
+        (Pubkey::new_unique(), system, false), // user
+        (token, loader, true),                 // token_x_program
+        (token, loader, true),                 // token_y_program
+        (Pubkey::new_unique(), system, false), // event_authority
+        (dlmm, loader, true),                  // program
+    ]
+}
+
+#[test]
+fn meteora_dlmm_encodes_swap_and_swap_exact_out() {
+    use adapter::meteora_dlmm::{MeteoraDlmm, SWAP_EXACT_OUT_DISCRIMINATOR};
+    use adapter::DexAdapter;
+
+    let data = MeteoraDlmm::encode(&dummy_params(DexId::MeteoraDlmm, 5, 3)).unwrap();
+    assert_eq!(data[..8], adapter::SWAP_DISCRIMINATOR);
+    assert_eq!(data[8..], [5u64.to_le_bytes(), 3u64.to_le_bytes()].concat());
+    assert_eq!(MeteoraDlmm::token_accounts(&data).unwrap(), (4, 5));
+
+    // `swap_exact_out(max_in_amount, out_amount)`.
+    let exact_out = SwapParams::MeteoraDlmm {
+        amount: 5,
+        other_amount_threshold: 9,
+        base_input: false,
+    };
+    let data = MeteoraDlmm::encode(&exact_out).unwrap();
+    assert_eq!(data[..8], SWAP_EXACT_OUT_DISCRIMINATOR);
+    assert_eq!(data[8..], [9u64.to_le_bytes(), 5u64.to_le_bytes()].concat());
+    MeteoraDlmm::check_instruction(&data).unwrap();
+
+    // An exact-out leg targets `min_out` and caps its input at `in_amount`.
+    let mut leg = dummy_leg(DexId::MeteoraDlmm, 9, 5, 0);
+    leg.params = exact_out;
+    adapter::check_params(&leg).expect("exact-out DLMM leg rejected");
+    assert!(adapter::set_in_amount(&mut leg, 10).is_err());
+}
+
+#[test]
+fn meteora_dlmm_validates_pair_and_variable_bin_arrays() {
+    use adapter::meteora_dlmm::{MeteoraDlmm, METEORA_DLMM_PROGRAM_ID};
+
+    let dlmm = METEORA_DLMM_PROGRAM_ID;
+    let token = anchor_spl::token::ID;
+    let entry = adapter::default_entry(DexId::MeteoraDlmm);
+    let data = adapter::SWAP_DISCRIMINATOR;
+    let validate =
+        |layout: &[(Pubkey, Pubkey, bool)]| validate_layout::<MeteoraDlmm>(&data, layout, &entry);
+
+    // No bin array: the swap has no liquidity to cross.
+    let mut layout = dlmm_swap_layout();
+    assert!(validate(&layout).is_err());
+
+    for _ in 0..3 {
+        layout.push((Pubkey::new_unique(), dlmm, false));
+        validate(&layout).expect("valid swap accounts rejected");
+    }
+
+    // Optional accounts may also be set.
+    let mut with_optionals = layout.clone();
+    with_optionals[1] = (Pubkey::new_unique(), dlmm, false);
+    with_optionals[9] = (Pubkey::new_unique(), token, false);
+    validate(&with_optionals).expect("bitmap extension / host fee rejected");
+
+    // An lb_pair that is not a DLMM account.
+    let mut bad = layout.clone();
+    bad[0].1 = token;
+    assert!(validate(&bad).is_err());
+
+    // A bin array owned by another program.
+    let mut bad = layout.clone();
+    bad[16].1 = Pubkey::new_unique();
+    assert!(validate(&bad).is_err());
+
+    // A bitmap extension that is neither `None` nor a DLMM account.
+    let mut bad = layout.clone();
+    bad[1] = (Pubkey::new_unique(), token, false);
+    assert!(validate(&bad).is_err());
+
+    // A different program in the `program` slot.
+    let mut bad = layout.clone();
+    bad[14].0 = Pubkey::new_unique();
+    assert!(validate(&bad).is_err());
+}
+
+#[test]
+fn meteora_dlmm_leg_consumes_exactly_its_bin_arrays() {
+    use adapter::meteora_dlmm::{MeteoraDlmm, METEORA_DLMM_PROGRAM_ID};
+    let entry = adapter::default_entry(DexId::MeteoraDlmm);
+    let bin_arrays =
+        |n: usize| (0..n).map(|_| (Pubkey::new_unique(), METEORA_DLMM_PROGRAM_ID, false));
+
+    for n in 1..=4usize {
+        let leg = dummy_leg(DexId::MeteoraDlmm, 1_000, 0, (15 + n) as u8);
+        let data = adapter::encode(&leg).unwrap();
+        // The leg's accounts are followed by the next DLMM leg's.
+        let mut layout = dlmm_swap_layout();
+        layout.extend(bin_arrays(n));
+        layout.extend(dlmm_swap_layout());
+        layout.extend(bin_arrays(1));
+
+        let accounts = layout_accounts(&layout);
+        let consumed = adapter::invoke_leg(&leg, data.clone(), &accounts, &entry).unwrap();
+        assert_eq!(consumed, 15 + n);
+
+        // The CPI carries the fixed accounts and exactly `n` bin arrays.
+        let ix = adapter::prepare::<MeteoraDlmm>(data.clone(), &accounts[..consumed], &entry)
+            .expect("valid DLMM leg rejected");
+        assert_eq!(ix.program_id, METEORA_DLMM_PROGRAM_ID);
+        assert_eq!(ix.data, data);
+        let keys: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+        let expected: Vec<Pubkey> = layout[..15 + n].iter().map(|(key, _, _)| *key).collect();
+        assert_eq!(keys, expected);
+
+        // The next leg starts right after this one's bin arrays.
+        let next = dummy_leg(DexId::MeteoraDlmm, 1_000, 0, 15 + 1);
+        let rest = &accounts[consumed..];
+        assert_eq!(
+            adapter::invoke_leg(&next, adapter::encode(&next).unwrap(), rest, &entry).unwrap(),
+            16
+        );
+
+        // Declaring the bin arrays away leaves the swap without liquidity.
+        let short = dummy_leg(DexId::MeteoraDlmm, 1_000, 0, 15);
+        assert!(adapter::invoke_leg(&short, data.clone(), &accounts, &entry).is_err());
+    }
+
+    // Too few accounts left for the declared bin arrays.
+    let leg = dummy_leg(DexId::MeteoraDlmm, 1_000, 0, 15 + 3);
+    let mut layout = dlmm_swap_layout();
+    layout.extend(bin_arrays(2));
+    let accounts = layout_accounts(&layout);
+    let data = adapter::encode(&leg).unwrap();
+    assert!(adapter::invoke_leg(&leg, data, &accounts, &entry).is_err());
+}
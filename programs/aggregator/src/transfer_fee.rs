@@ -0,0 +1,66 @@
+//! Token-2022 `TransferFeeConfig` helpers.
+//!
+//! Balance deltas measured by the router are already net of any transfer fee the
+//! mint withholds on the receiving account.  These helpers recover the gross
+//! amounts so `route` can report what the pools sent versus what the user netted.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions},
+    state::Mint,
+};
+use anchor_spl::token_2022::ID as TOKEN_2022_ID;
+
+use crate::error::AggregatorError;
+
+/// Transfer fee configuration of `mint`, or `None` for classic SPL mints and
+/// Token-2022 mints without the extension.
+pub fn config(mint: &AccountInfo<'_>) -> Result<Option<TransferFeeConfig>> {
+    if *mint.owner != TOKEN_2022_ID {
+        return Ok(None);
+    }
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<Mint>::unpack(&data)?;
+    Ok(state.get_extension::<TransferFeeConfig>().ok().copied())
+}
+
+/// Fee withheld when `amount` is sent in `epoch`.
+pub fn fee_on(config: Option<&TransferFeeConfig>, epoch: u64, amount: u64) -> Result<u64> {
+    match config {
+        Some(config) => Ok(config
+            .calculate_epoch_fee(epoch, amount)
+            .ok_or(AggregatorError::NumericalOverflow)?),
+        None => Ok(0),
+    }
+}
+
+/// Fee that was withheld on a transfer which credited `net_amount` in `epoch`.
+pub fn fee_withheld_from(
+    config: Option<&TransferFeeConfig>,
+    epoch: u64,
+    net_amount: u64,
+) -> Result<u64> {
+    match config {
+        Some(config) if net_amount > 0 => Ok(config
+            .calculate_inverse_epoch_fee(epoch, net_amount)
+            .ok_or(AggregatorError::NumericalOverflow)?),
+        _ => Ok(0),
+    }
+}
+
+/// Fee withheld across several transfers that credited `net_amounts` in `epoch`.
+///
+/// The fee is rounded and capped per transfer, so a split route's branches
+/// must be reversed one by one rather than on their sum.
+pub fn fee_withheld_from_each(
+    config: Option<&TransferFeeConfig>,
+    epoch: u64,
+    net_amounts: &[u64],
+) -> Result<u64> {
+    net_amounts.iter().try_fold(0u64, |total, &net_amount| {
+        let withheld = fee_withheld_from(config, epoch, net_amount)?;
+        Ok(total
+            .checked_add(withheld)
+            .ok_or(AggregatorError::NumericalOverflow)?)
+    })
+}
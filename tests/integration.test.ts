@@ -27,6 +27,7 @@ import {
   fetchOracle,
   getOracleAddress,
   getSwapV2Instruction,
+  getSwapV2InstructionDataDecoder,
   getTickArrayAddress,
   getWhirlpoolAddress,
 } from "@orca-so/whirlpools-client";
@@ -216,13 +217,29 @@ describe("integration: router behaviour (devnet)", function () {
     );
 
     const cfg = await programClient.fetchConfig(client.rpc, configPda);
+    // The fee is charged in the output mint unless the config says otherwise.
+    const feeMint =
+      cfg.data.feeSide === programClient.FeeSide.Input
+        ? devUSDC.mint
+        : devSAMO.mint;
 
-    const feeVaultAta = await client.getOrCreateAta(
+    const userSrcAta = await client.getOrCreateAta(
+      client.wallet,
+      devUSDC.mint,
+      client.wallet.address
+    );
+    const userDstAta = await client.getOrCreateAta(
       client.wallet,
       devSAMO.mint,
-      cfg.address
+      client.wallet.address
     );
+
+    // The router re-encodes the Orca swap from typed params, so decode the
+    // arguments the Orca SDK chose instead of forwarding its raw data.
     const orcaSwapIx = instructions[instructions.length - 1];
+    const orcaSwapArgs = getSwapV2InstructionDataDecoder().decode(
+      orcaSwapIx.data
+    );
     const orcaRemainingForLeg = [
       ...orcaSwapIx.accounts,
       {
@@ -230,25 +247,52 @@ describe("integration: router behaviour (devnet)", function () {
         role: 0,
       },
     ];
-    const orcaSwapLeg: programClient.SwapLeg = {
+    const orcaSwapLeg: programClient.SwapLegArgs = {
       dexId: programClient.DexId.OrcaWhirlpool,
       inAmount: amountIn,
       minOut: quote.tokenMinOut,
       accountCount: orcaSwapIx.accounts.length, // <-- IMPORTANT
-      data: orcaSwapIx.data, // raw CPI payload for Orca
+      params: programClient.swapParams("OrcaWhirlpool", {
+        amount: orcaSwapArgs.amount,
+        otherAmountThreshold: orcaSwapArgs.otherAmountThreshold,
+        sqrtPriceLimit: orcaSwapArgs.sqrtPriceLimit,
+        amountSpecifiedIsInput: orcaSwapArgs.amountSpecifiedIsInput,
+        aToB: orcaSwapArgs.aToB,
+        v2: true,
+      }),
       inMint: devUSDC.mint,
       outMint: devSAMO.mint,
     };
-    const routeIx = programClient.getRouteInstruction({
-      config: configPda,
-      feeVault: feeVaultAta,
-      legs: [orcaSwapLeg],
-      userDestination: userDstAta,
-      userSource: userSrcAta,
-      userAuthority: client.wallet,
-      userMaxIn: quote.tokenIn,
-      userMinOut: quote.tokenMinOut,
-    });
+    const routeIx = await utils.getRouteInstruction(
+      {
+        userAuthority: client.wallet,
+        userSource: userSrcAta,
+        userDestination: userDstAta,
+        payer: client.wallet,
+        destinationMint: devSAMO.mint,
+        feeMint,
+        feeTokenProgram: TOKEN_PROGRAM_ADDRESS,
+        feeTier: await utils.getFeeTierAddress(devUSDC.mint, devSAMO.mint),
+        defaultFeeTier: await utils.getFeeTierAddress(
+          utils.ANY_MINT,
+          devSAMO.mint
+        ),
+        config: configPda,
+        tokenProgram: TOKEN_PROGRAM_ADDRESS,
+        legs: [orcaSwapLeg],
+        userMaxIn: quote.tokenIn,
+        userMinOut: quote.tokenMinOut,
+        options: {
+          wrapLamports: 0,
+          unwrapSol: false,
+          referralBps: 0,
+          quotedOut: 0,
+          validUntilSlot: 0,
+          validUntilTs: 0,
+        },
+      },
+      orcaRemainingForLeg
+    );
 
     const transactionMessage = pipe(
       createTransactionMessage({ version: 0 }),
@@ -371,12 +415,15 @@ describe("integration: router behaviour (devnet)", function () {
   //     },
   //   ];
 
-  //   const orcaSwapLeg: programClient.SwapLeg = {
+  //   const orcaSwapLeg: programClient.SwapLegArgs = {
   //     dexId: programClient.DexId.OrcaWhirlpool,
   //     inAmount: amountIn,
   //     minOut: quote.tokenMinOut,
   //     accountCount: orcaRemainingForLeg.length, // <-- IMPORTANT
-  //     data: orcaSwapIx.data, // raw CPI payload for Orca
+  //     params: programClient.swapParams("OrcaWhirlpool", {
+  //       ...getSwapV2InstructionDataDecoder().decode(orcaSwapIx.data),
+  //       v2: true,
+  //     }),
   //     inMint: devUSDC.mint,
   //     outMint: devSAMO.mint,
   //   };
@@ -394,24 +441,34 @@ describe("integration: router behaviour (devnet)", function () {
   //     devSAMO.mint,
   //     client.wallet.address
   //   );
-  //   // Fetch config to know who the admin is (fee vault must be admin’s ATA for OUT mint)
-  //   const cfg = await programClient.fetchConfig(client.rpc, configPda); // or your generated getter
-  //   const feeVaultAta = await client.getOrCreateAta(
-  //     client.wallet,
-  //     devSAMO.mint,
-  //     cfg.address
-  //   );
   //   // Base route instruction (declared accounts only)
-  //   let routeIx = utils.getRouteInstruction(
+  //   let routeIx = await utils.getRouteInstruction(
   //     {
   //       config: configPda,
-  //       feeVault: feeVaultAta, // admin’s ATA for final out mint
+  //       payer: client.wallet,
+  //       destinationMint: devSAMO.mint,
+  //       feeMint: devSAMO.mint,
+  //       feeTokenProgram: TOKEN_PROGRAM_ADDRESS,
+  //       feeTier: await utils.getFeeTierAddress(devUSDC.mint, devSAMO.mint),
+  //       defaultFeeTier: await utils.getFeeTierAddress(
+  //         utils.ANY_MINT,
+  //         devSAMO.mint
+  //       ),
+  //       tokenProgram: TOKEN_PROGRAM_ADDRESS,
   //       legs: [orcaSwapLeg], // your single-leg route
   //       userDestination: userDstAta, // <-- token account, not mint
   //       userSource: userSrcAta, // <-- token account, not mint
   //       userAuthority: client.wallet,
   //       userMaxIn: quote.tokenIn,
   //       userMinOut: quote.tokenMinOut,
+  //       options: {
+  //         wrapLamports: 0,
+  //         unwrapSol: false,
+  //         referralBps: 0,
+  //         quotedOut: 0,
+  //         validUntilSlot: 0,
+  //         validUntilTs: 0,
+  //       },
   //     },
   //     orcaRemainingForLeg
   //   );
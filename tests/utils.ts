@@ -1,17 +1,17 @@
 import { orderMints } from "@orca-so/whirlpools";
-import { AccountMeta, AccountRole, Address, address } from "@solana/kit";
+import {
+  AccountMeta,
+  Address,
+  address,
+  getAddressEncoder,
+  getProgramDerivedAddress,
+} from "@solana/kit";
 import "dotenv/config";
 import {
   AGGREGATOR_PROGRAM_ADDRESS,
-  getRouteInstructionDataEncoder,
-  RouteInput,
-  RouteInstruction,
-  RouteInstructionDataArgs,
+  getRouteInstructionAsync,
+  RouteAsyncInput,
 } from "../clients/generated/aggregator";
-import {
-  getAccountMetaFactory,
-  ResolvedAccount,
-} from "../clients/generated/aggregator/shared";
 
 export const stringify = (obj: any) => {
   const bigIntReplacer = (key: string, value: any) =>
@@ -42,93 +42,39 @@ export const OrcaTestPoolAddress = address(
 export const ANCHOR_PROVIDER_URL =
   process.env.ANCHOR_PROVIDER_URL || "https://api.devnet.solana.com";
 
-export function getRouteInstruction<
-  TAccountUserAuthority extends string,
-  TAccountUserSource extends string,
-  TAccountUserDestination extends string,
-  TAccountFeeVault extends string,
-  TAccountConfig extends string,
-  TAccountTokenProgram extends string,
-  TProgramAddress extends Address = typeof AGGREGATOR_PROGRAM_ADDRESS,
+// `Pubkey::default()`, the input mint of the any-input fee tier.
+export const ANY_MINT = address("11111111111111111111111111111111");
+
+/** Derives the `[b"fee_tier", in_mint, out_mint]` PDA read by the route. */
+export async function getFeeTierAddress(
+  inMint: Address,
+  outMint: Address
+): Promise<Address> {
+  const [feeTier] = await getProgramDerivedAddress({
+    programAddress: AGGREGATOR_PROGRAM_ADDRESS,
+    seeds: [
+      "fee_tier",
+      getAddressEncoder().encode(inMint),
+      getAddressEncoder().encode(outMint),
+    ],
+  });
+  return feeTier;
+}
+
+/**
+ * Builds a `route` instruction with the legs' accounts appended as remaining
+ * accounts, in leg order.
+ */
+export async function getRouteInstruction<
   TRemainingAccounts extends readonly AccountMeta<string>[] = []
 >(
-  input: RouteInput<
-    TAccountUserAuthority,
-    TAccountUserSource,
-    TAccountUserDestination,
-    TAccountFeeVault,
-    TAccountConfig,
-    TAccountTokenProgram
-  >,
+  input: RouteAsyncInput,
   remainingAccounts: TRemainingAccounts,
-  config?: { programAddress?: TProgramAddress }
-): RouteInstruction<
-  TProgramAddress,
-  TAccountUserAuthority,
-  TAccountUserSource,
-  TAccountUserDestination,
-  TAccountFeeVault,
-  TAccountConfig,
-  TAccountTokenProgram,
-  TRemainingAccounts
-> {
-  // Program address.
-  const programAddress = config?.programAddress ?? AGGREGATOR_PROGRAM_ADDRESS;
-
-  // Original accounts.
-  const originalAccounts = {
-    userAuthority: { value: input.userAuthority ?? null, isWritable: false },
-    userSource: { value: input.userSource ?? null, isWritable: true },
-    userDestination: {
-      value: input.userDestination ?? null,
-      isWritable: true,
-    },
-    feeVault: { value: input.feeVault ?? null, isWritable: true },
-    config: { value: input.config ?? null, isWritable: false },
-    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  config?: { programAddress?: Address }
+) {
+  const instruction = await getRouteInstructionAsync(input, config);
+  return {
+    ...instruction,
+    accounts: [...instruction.accounts, ...remainingAccounts],
   };
-  const accounts = originalAccounts as Record<
-    keyof typeof originalAccounts,
-    ResolvedAccount
-  >;
-
-  // Original args.
-  const args = { ...input };
-
-  // Resolve default values.
-  if (!accounts.tokenProgram.value) {
-    accounts.tokenProgram.value =
-      "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA" as Address<"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA">;
-  }
-
-  const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
-  const instruction = {
-    accounts: [
-      getAccountMeta(accounts.userAuthority),
-      getAccountMeta(accounts.userSource),
-      getAccountMeta(accounts.userDestination),
-      getAccountMeta(accounts.feeVault),
-      getAccountMeta(accounts.config),
-      getAccountMeta(accounts.tokenProgram),
-      ...remainingAccounts,
-    ],
-    programAddress,
-    data: getRouteInstructionDataEncoder().encode(
-      args as RouteInstructionDataArgs
-    ),
-  } as RouteInstruction<
-    TProgramAddress,
-    TAccountUserAuthority,
-    TAccountUserSource,
-    TAccountUserDestination,
-    TAccountFeeVault,
-    TAccountConfig,
-    TAccountTokenProgram,
-    TRemainingAccounts
-  >;
-  console.log(
-    "instruction inside getRouteInstruction",
-    JSON.stringify(instruction)
-  );
-  return instruction;
 }